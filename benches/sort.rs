@@ -0,0 +1,118 @@
+//! # sort benchmarks.
+//!
+//! Benchmarks every sort over random, sorted, reverse sorted and few unique
+//! inputs, at sizes from 100 to 100k elements. The sorts that are O(n^2) on
+//! any of the inputs stop at 10k, since at 100k a single iteration can take
+//! minutes.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use dsa::sort::{
+    merge_sort_with_buffer, BinaryInsertionSort, BubbleSort, CocktailSort, CombSort, CycleSort,
+    GnomeSort, HeapSort, InsertionSort, IntroSort, MergeSort, MergeSortIterative, NaturalMergeSort,
+    PancakeSort, QuickSort, QuickSortThreeWay, SelectionSort, ShellSort, Sorter,
+};
+
+const SIZES: [usize; 4] = [100, 1_000, 10_000, 100_000];
+
+/// The largest size the O(n^2) sorts are run at.
+const QUADRATIC_MAX_SIZE: usize = 10_000;
+
+/// The shapes of input each sort is run over.
+const INPUTS: [Input; 4] = [Input::Random, Input::Sorted, Input::Reversed, Input::FewUnique];
+
+#[derive(Debug, Clone, Copy)]
+enum Input {
+    Random,
+    Sorted,
+    Reversed,
+    FewUnique,
+}
+
+impl Input {
+    fn name(self) -> &'static str {
+        match self {
+            Input::Random => "random",
+            Input::Sorted => "sorted",
+            Input::Reversed => "reversed",
+            Input::FewUnique => "few_unique",
+        }
+    }
+
+    /// Returns `n` elements of this shape. Random values come from a
+    /// fixed-seed xorshift generator, so every sort sees identical data.
+    fn generate(self, n: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        match self {
+            Input::Random => (0..n).map(|_| random()).collect(),
+            Input::Sorted => (0..n as u32).collect(),
+            Input::Reversed => (0..n as u32).rev().collect(),
+            Input::FewUnique => (0..n).map(|_| random() % 4).collect(),
+        }
+    }
+}
+
+/// Benchmarks `S` over every input shape, at every size up to `max_size`.
+fn bench_sorter<S: Sorter>(c: &mut Criterion, name: &str, max_size: usize) {
+    let mut group = c.benchmark_group(name);
+    for input in INPUTS {
+        for n in SIZES.into_iter().filter(|&n| n <= max_size) {
+            let data = input.generate(n);
+            group.bench_with_input(BenchmarkId::new(input.name(), n), &data, |b, data| {
+                b.iter_batched_ref(|| data.clone(), |list| S::sort(list), BatchSize::LargeInput);
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Benchmarks `merge_sort_with_buffer` over random inputs, with one scratch
+/// buffer reused across every iteration.
+///
+/// Compare against `merge_sort`, which allocates a buffer per sort. The
+/// difference is the cost of that allocation, which shrinks relative to the
+/// sort as n grows.
+fn bench_merge_sort_with_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("merge_sort_with_buffer");
+    let mut scratch = Vec::with_capacity(SIZES[SIZES.len() - 1]);
+    for n in SIZES {
+        let data = Input::Random.generate(n);
+        group.bench_with_input(BenchmarkId::new(Input::Random.name(), n), &data, |b, data| {
+            b.iter_batched_ref(
+                || data.clone(),
+                |list| merge_sort_with_buffer(list, &mut scratch),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn sort_benchmarks(c: &mut Criterion) {
+    bench_sorter::<BubbleSort>(c, "bubble_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<CocktailSort>(c, "cocktail_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<CombSort>(c, "comb_sort", usize::MAX);
+    bench_sorter::<InsertionSort>(c, "insertion_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<BinaryInsertionSort>(c, "binary_insertion_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<GnomeSort>(c, "gnome_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<SelectionSort>(c, "selection_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<CycleSort>(c, "cycle_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<PancakeSort>(c, "pancake_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<ShellSort>(c, "shell_sort", usize::MAX);
+    bench_sorter::<MergeSort>(c, "merge_sort", usize::MAX);
+    bench_sorter::<MergeSortIterative>(c, "merge_sort_iterative", usize::MAX);
+    bench_sorter::<NaturalMergeSort>(c, "natural_merge_sort", usize::MAX);
+    // Quadratic on the few unique inputs.
+    bench_sorter::<QuickSort>(c, "quick_sort", QUADRATIC_MAX_SIZE);
+    bench_sorter::<QuickSortThreeWay>(c, "quick_sort_three_way", usize::MAX);
+    bench_sorter::<HeapSort>(c, "heap_sort", usize::MAX);
+    bench_sorter::<IntroSort>(c, "intro_sort", usize::MAX);
+}
+
+criterion_group!(benches, sort_benchmarks, bench_merge_sort_with_buffer);
+criterion_main!(benches);
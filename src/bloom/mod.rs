@@ -0,0 +1,147 @@
+//! # Bloom filter data structures.
+//!
+//! `bloom` defines a Bloom filter, a probabilistic set membership structure
+//! over hashable values.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A set that can report values as present that were never inserted, but
+/// never reports an inserted value as absent.
+///
+/// The filter is an array of `m` bits, all initially unset. Inserting a value
+/// sets the `k` bits its `k` hashes index, and a value is reported present if
+/// all `k` of its bits are set. An absent value is only reported present if
+/// other values happen to have set all of its bits.
+///
+/// For `n` expected values and a false positive rate `p`, the array is sized
+/// to `m = -n ln(p) / ln(2)^2` bits, and `k = (m / n) ln(2)` hashes minimises
+/// the false positive rate for that size.
+///
+/// Rather than `k` independent hash functions, the `k` hashes are made from
+/// two base hashes by double hashing, where the `i`th hash is `h1 + i * h2`,
+/// which keeps the same asymptotic false positive rate.
+///
+/// Insert: O(k).
+/// Contains: O(k).
+#[derive(Debug, Clone)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    num_bits: usize,
+    num_hashes: usize,
+}
+
+impl BloomFilter {
+    /// Creates an empty filter sized for `expected_items` values with a false
+    /// positive rate of `false_positive_rate`.
+    ///
+    /// Panics if `expected_items` is 0, or `false_positive_rate` is not
+    /// strictly between 0 and 1.
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        assert!(expected_items > 0, "expected items must be non-zero");
+        assert!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "false positive rate must be between 0 and 1"
+        );
+
+        let n = expected_items as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil() as usize;
+        let num_hashes = ((num_bits as f64 / n) * ln2).round().max(1.0) as usize;
+        Self { bits: vec![0; num_bits.div_ceil(64)], num_bits, num_hashes }
+    }
+
+    /// Inserts `item` into the filter.
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for i in self.bit_indices(item) {
+            self.bits[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    /// Returns `true` if `item` may have been inserted, or `false` if it
+    /// definitely wasn't.
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        self.bit_indices(item).all(|i| self.bits[i / 64] & (1 << (i % 64)) != 0)
+    }
+
+    /// Returns the number of bits in the filter, `m`.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Returns the number of hashes per value, `k`.
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    /// Returns the indices of the `k` bits for `item`.
+    fn bit_indices<T: Hash + ?Sized>(&self, item: &T) -> impl Iterator<Item = usize> {
+        let h1 = hash(item, 0);
+        let h2 = hash(item, 1);
+        let m = self.num_bits as u64;
+        (0..self.num_hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % m) as usize)
+    }
+}
+
+/// Hashes `item`, with `seed` selecting one of several independent hashes.
+fn hash<T: Hash + ?Sized>(item: &T, seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    item.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter() {
+        let mut filter = BloomFilter::new(100, 0.01);
+        assert!(!filter.contains("apple"));
+
+        filter.insert("apple");
+        filter.insert("banana");
+        assert!(filter.contains("apple"));
+        assert!(filter.contains("banana"));
+        assert!(!filter.contains("cherry"));
+
+        filter.insert(&42);
+        assert!(filter.contains(&42));
+    }
+
+    #[test]
+    fn test_bloom_filter_sizing() {
+        let filter = BloomFilter::new(1000, 0.01);
+        assert_eq!(9586, filter.num_bits());
+        assert_eq!(7, filter.num_hashes());
+
+        // A rate barely below 1 still needs at least one bit and hash.
+        let filter = BloomFilter::new(1, 0.99);
+        assert_eq!(1, filter.num_bits());
+        assert_eq!(1, filter.num_hashes());
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_rate() {
+        let (n, rate) = (1000, 0.01);
+        let mut filter = BloomFilter::new(n, rate);
+        for i in 0..n {
+            filter.insert(&i);
+        }
+
+        // No false negatives.
+        assert!((0..n).all(|i| filter.contains(&i)));
+
+        let absent = 10_000;
+        let false_positives = (n..n + absent).filter(|i| filter.contains(i)).count();
+        let measured = false_positives as f64 / absent as f64;
+        assert!(measured < 2.0 * rate, "false positive rate {measured} exceeds {rate}");
+    }
+
+    #[test]
+    #[should_panic(expected = "between 0 and 1")]
+    fn test_bloom_filter_invalid_rate() {
+        BloomFilter::new(10, 1.0);
+    }
+}
@@ -0,0 +1,220 @@
+//! # least recently used cache.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Marks the end of the recency list.
+const NIL: usize = usize::MAX;
+
+/// A bounded map that, once full, evicts its least recently used entry to
+/// make room for a new one.
+///
+/// The entries live in a `Vec`, linked into a doubly linked list by index,
+/// ordered from most to least recently used. A `HashMap` finds the index of a
+/// key's entry, which can then be unlinked and relinked at the front of the
+/// list in O(1). Evicting the entry at the back of the list frees its slot
+/// for the new entry, so slots are never left empty.
+///
+/// Get: O(1).
+/// Put: O(1).
+#[derive(Debug, Clone)]
+pub struct LruCache<K: Eq + Hash, V> {
+    map: HashMap<K, usize>,
+    entries: Vec<Entry<K, V>>,
+    // The index of the most recently used entry.
+    head: usize,
+    // The index of the least recently used entry.
+    tail: usize,
+    capacity: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    // The index of the next more recently used entry.
+    prev: usize,
+    // The index of the next less recently used entry.
+    next: usize,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Creates an empty cache that can hold `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+            capacity,
+        }
+    }
+
+    /// Returns the value of `key`, or `None` if it isn't cached, marking it as
+    /// the most recently used entry.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let i = *self.map.get(key)?;
+        self.unlink(i);
+        self.push_front(i);
+        Some(&self.entries[i].value)
+    }
+
+    /// Caches `value` under `key`, marking it as the most recently used entry.
+    /// If `key` is already cached, its value is replaced.
+    ///
+    /// Returns the least recently used entry if it was evicted to make room,
+    /// or the new entry itself if the capacity is 0.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&i) = self.map.get(&key) {
+            self.entries[i].value = value;
+            self.unlink(i);
+            self.push_front(i);
+            return None;
+        }
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+
+        let entry = Entry { key: key.clone(), value, prev: NIL, next: NIL };
+        let (i, evicted) = if self.entries.len() < self.capacity {
+            self.entries.push(entry);
+            (self.entries.len() - 1, None)
+        } else {
+            let i = self.tail;
+            self.unlink(i);
+            let evicted = std::mem::replace(&mut self.entries[i], entry);
+            self.map.remove(&evicted.key);
+            (i, Some((evicted.key, evicted.value)))
+        };
+        self.map.insert(key, i);
+        self.push_front(i);
+        evicted
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the number of entries the cache can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes the entry at `i` from the recency list.
+    fn unlink(&mut self, i: usize) {
+        let Entry { prev, next, .. } = self.entries[i];
+        match prev {
+            NIL => self.head = next,
+            prev => self.entries[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.entries[next].prev = prev,
+        }
+    }
+
+    /// Inserts the unlinked entry at `i` at the front of the recency list.
+    fn push_front(&mut self, i: usize) {
+        self.entries[i].prev = NIL;
+        self.entries[i].next = self.head;
+        match self.head {
+            NIL => self.tail = i,
+            head => self.entries[head].prev = i,
+        }
+        self.head = i;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::with_capacity(2);
+        assert!(cache.is_empty());
+        assert_eq!(None, cache.put("A", 1));
+        assert_eq!(None, cache.put("B", 2));
+        assert_eq!(Some(("A", 1)), cache.put("C", 3));
+        assert_eq!(None, cache.get(&"A"));
+
+        // Using B makes C the least recently used.
+        assert_eq!(Some(&2), cache.get(&"B"));
+        assert_eq!(Some(("C", 3)), cache.put("D", 4));
+        assert_eq!(2, cache.len());
+        assert_eq!(Some(&2), cache.get(&"B"));
+        assert_eq!(Some(&4), cache.get(&"D"));
+    }
+
+    #[test]
+    fn test_lru_cache_evicts_after_miss() {
+        let mut cache = LruCache::with_capacity(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        // A was evicted by C, so this is a miss and leaves the order alone.
+        assert_eq!(None, cache.get(&"A"));
+        assert_eq!(Some(("B", 2)), cache.put("D", 4));
+        assert_eq!(Some(&3), cache.get(&"C"));
+        assert_eq!(Some(&4), cache.get(&"D"));
+    }
+
+    #[test]
+    fn test_lru_cache_get_marks_used() {
+        let mut cache = LruCache::with_capacity(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        assert_eq!(Some(&1), cache.get(&"A"));
+        assert_eq!(Some(("B", 2)), cache.put("C", 3));
+        assert_eq!(None, cache.get(&"B"));
+        assert_eq!(Some(&1), cache.get(&"A"));
+        assert_eq!(Some(&3), cache.get(&"C"));
+    }
+
+    #[test]
+    fn test_lru_cache_put_existing() {
+        let mut cache = LruCache::with_capacity(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        // Replacing A's value marks it as used, without evicting anything.
+        assert_eq!(None, cache.put("A", 10));
+        assert_eq!(2, cache.len());
+        assert_eq!(Some(("B", 2)), cache.put("C", 3));
+        assert_eq!(Some(&10), cache.get(&"A"));
+    }
+
+    #[test]
+    fn test_lru_cache_eviction_order() {
+        let mut cache = LruCache::with_capacity(3);
+        for i in 0..3 {
+            cache.put(i, i * 10);
+        }
+        cache.get(&0);
+        cache.get(&1);
+        // The recency order is now 1, 0, 2, so 2 goes first.
+        assert_eq!(Some((2, 20)), cache.put(3, 30));
+        assert_eq!(Some((0, 0)), cache.put(4, 40));
+        assert_eq!(Some((1, 10)), cache.put(5, 50));
+        assert_eq!(Some((3, 30)), cache.put(6, 60));
+    }
+
+    #[test]
+    fn test_lru_cache_capacity() {
+        let mut cache = LruCache::with_capacity(1);
+        assert_eq!(1, cache.capacity());
+        cache.put('a', 1);
+        assert_eq!(Some(('a', 1)), cache.put('b', 2));
+        assert_eq!(Some(&2), cache.get(&'b'));
+
+        let mut cache = LruCache::with_capacity(0);
+        assert_eq!(Some(('a', 1)), cache.put('a', 1));
+        assert!(cache.is_empty());
+        assert_eq!(None, cache.get(&'a'));
+    }
+}
@@ -0,0 +1,5 @@
+//! # cache data structures.
+//!
+//! `cache` defines various bounded caches on generic types.
+
+pub mod lru;
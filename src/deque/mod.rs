@@ -0,0 +1,248 @@
+//! # double-ended queue data structures.
+//!
+//! `deque` defines a double-ended queue over a generic type.
+
+use std::ops::{Index, IndexMut};
+
+/// The number of slots allocated by the first push.
+const MIN_CAPACITY: usize = 4;
+
+/// A double-ended queue backed by a growable ring buffer.
+///
+/// Like [`RingBuffer`](crate::queue::RingBuffer), the elements sit in a
+/// buffer from a head index onwards, wrapping around to the start of the
+/// buffer once they reach its end. Pushing to the back fills the slot after
+/// the last element, and pushing to the front fills the slot before the head
+/// and moves the head back to it, wrapping around to the end of the buffer.
+///
+/// When the buffer is full, a push first moves the elements into a buffer of
+/// double the capacity, unwrapping them so the head starts at index 0.
+///
+/// Push front: O(1) amortised.
+/// Push back: O(1) amortised.
+/// Pop front: O(1).
+/// Pop back: O(1).
+/// Index: O(1).
+#[derive(Debug, Clone)]
+pub struct Deque<T> {
+    slots: Vec<Option<T>>,
+    // The index of the front element.
+    head: usize,
+    len: usize,
+}
+
+impl<T> Deque<T> {
+    /// Creates an empty deque, which doesn't allocate until the first push.
+    pub fn new() -> Self {
+        Self { slots: Vec::new(), head: 0, len: 0 }
+    }
+
+    /// Pushes `item` onto the front of the deque.
+    pub fn push_front(&mut self, item: T) {
+        self.grow_if_full();
+        self.head = (self.head + self.capacity() - 1) % self.capacity();
+        self.slots[self.head] = Some(item);
+        self.len += 1;
+    }
+
+    /// Pushes `item` onto the back of the deque.
+    pub fn push_back(&mut self, item: T) {
+        self.grow_if_full();
+        let tail = self.slot(self.len);
+        self.slots[tail] = Some(item);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        item
+    }
+
+    /// Removes and returns the back element, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let tail = self.slot(self.len);
+        self.slots[tail].take()
+    }
+
+    /// Returns the element `i` places from the front, or `None` if `i` is out
+    /// of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i >= self.len {
+            return None;
+        }
+        self.slots[self.slot(i)].as_ref()
+    }
+
+    /// Returns a mutable reference to the element `i` places from the front,
+    /// or `None` if `i` is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let slot = self.slot(i);
+        self.slots[slot].as_mut()
+    }
+
+    /// Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the deque can hold before it grows.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the index of the slot holding the element `i` places from the
+    /// front.
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % self.capacity()
+    }
+
+    /// Doubles the capacity if the deque is full, moving the elements to the
+    /// start of the new buffer, in order.
+    fn grow_if_full(&mut self) {
+        if self.len < self.capacity() {
+            return;
+        }
+
+        let capacity = (2 * self.capacity()).max(MIN_CAPACITY);
+        let mut slots = Vec::with_capacity(capacity);
+        // Read the old buffer from the head, wrapping around, to unwrap it.
+        let (wrapped, from_head) = self.slots.split_at_mut(self.head);
+        slots.extend(from_head.iter_mut().chain(wrapped).map(Option::take));
+        slots.resize_with(capacity, || None);
+
+        self.slots = slots;
+        self.head = 0;
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    /// Panics if `i` is out of bounds.
+    fn index(&self, i: usize) -> &T {
+        self.get(i).unwrap_or_else(|| panic!("index {i} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    /// Panics if `i` is out of bounds.
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i).unwrap_or_else(|| panic!("index {i} is out of bounds"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the elements of `deque`, from front to back.
+    fn to_vec<T: Clone>(deque: &Deque<T>) -> Vec<T> {
+        (0..deque.len()).map(|i| deque[i].clone()).collect()
+    }
+
+    #[test]
+    fn test_deque() {
+        let mut deque = Deque::new();
+        assert!(deque.is_empty());
+        assert_eq!(None, deque.pop_front());
+        assert_eq!(None, deque.pop_back());
+        assert_eq!(None, deque.get(0));
+
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+        assert_eq!(3, deque.len());
+        assert_eq!(vec![1, 2, 3], to_vec(&deque));
+
+        deque[1] = 20;
+        assert_eq!(Some(&20), deque.get(1));
+        assert_eq!(Some(1), deque.pop_front());
+        assert_eq!(Some(3), deque.pop_back());
+        assert_eq!(Some(20), deque.pop_back());
+        assert_eq!(None, deque.pop_front());
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_deque_grow_while_wrapped() {
+        let mut deque = Deque::new();
+        for i in 0..MIN_CAPACITY {
+            deque.push_back(i);
+        }
+        // Free the first slot and refill it from the back, so the elements wrap
+        // around the end of a full buffer.
+        assert_eq!(Some(0), deque.pop_front());
+        deque.push_back(MIN_CAPACITY);
+        assert_eq!(MIN_CAPACITY, deque.capacity());
+
+        // Growing now has to unwrap the elements.
+        deque.push_front(0);
+        assert_eq!(2 * MIN_CAPACITY, deque.capacity());
+        assert_eq!((0..=MIN_CAPACITY).collect::<Vec<_>>(), to_vec(&deque));
+
+        deque.push_front(100);
+        deque.push_back(200);
+        assert_eq!(Some(100), deque.pop_front());
+        assert_eq!(Some(200), deque.pop_back());
+        assert_eq!((0..=MIN_CAPACITY).collect::<Vec<_>>(), to_vec(&deque));
+    }
+
+    #[test]
+    fn test_deque_interleaved() {
+        // Check every operation against a model, across many grows.
+        let mut deque = Deque::new();
+        let mut model = std::collections::VecDeque::new();
+        for i in 0..2000 {
+            match i % 7 {
+                0 | 3 => {
+                    deque.push_front(i);
+                    model.push_front(i);
+                }
+                1 | 4 | 5 => {
+                    deque.push_back(i);
+                    model.push_back(i);
+                }
+                2 => assert_eq!(model.pop_front(), deque.pop_front()),
+                _ => assert_eq!(model.pop_back(), deque.pop_back()),
+            }
+            assert_eq!(model.len(), deque.len());
+            assert_eq!(model.front(), deque.get(0));
+            assert_eq!(model.back(), deque.len().checked_sub(1).and_then(|i| deque.get(i)));
+        }
+        assert_eq!(Vec::from(model), to_vec(&deque));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of bounds")]
+    fn test_deque_index_out_of_bounds() {
+        let mut deque = Deque::new();
+        for i in 0..3 {
+            deque.push_back(i);
+        }
+        let _ = deque[3];
+    }
+}
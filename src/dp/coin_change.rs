@@ -0,0 +1,92 @@
+//! # coin change.
+
+/// Returns the fewest coins of the denominations `coins` adding up to
+/// `amount`, or `None` if no combination does, with an unlimited supply of
+/// each denomination.
+///
+/// Fill a table where entry `a` is the fewest coins adding up to `a`. An
+/// amount of 0 takes no coins, and each larger amount takes one more coin
+/// than the best of the amounts left after using each denomination that
+/// fits.
+///
+/// Zero denominations are ignored, since they never reduce the amount.
+///
+/// Worst-case performance: O(nA), for n denominations and an amount of A.
+/// Worst-case space complexity: O(A) auxiliary.
+pub fn min_coins(coins: &[u32], amount: u32) -> Option<u32> {
+    let amount = amount as usize;
+    let mut fewest: Vec<Option<u32>> = vec![None; amount + 1];
+    fewest[0] = Some(0);
+    for a in 1..=amount {
+        fewest[a] = coins
+            .iter()
+            .map(|&coin| coin as usize)
+            .filter(|&coin| coin != 0 && coin <= a)
+            .filter_map(|coin| fewest[a - coin])
+            .min()
+            .map(|count| count + 1);
+    }
+    fewest[amount]
+}
+
+/// Returns the number of distinct combinations of coins of the denominations
+/// `coins` adding up to `amount`, with an unlimited supply of each
+/// denomination.
+///
+/// Combinations are unordered, so 1 + 2 and 2 + 1 are the same combination.
+/// Fill a table where entry `a` is the number of ways to make `a`, adding one
+/// denomination at a time. With the denominations so far, the ways to make an
+/// amount using the new one are the ways to make what's left after one of
+/// it, so add those, in increasing order of amount so the new denomination
+/// can be used repeatedly. Considering denominations in an outer loop, rather
+/// than amounts, counts each combination once rather than once per order.
+///
+/// An amount of 0 can be made one way, with no coins. Zero denominations are
+/// ignored, since they'd allow infinitely many combinations.
+///
+/// Panics if the number of combinations overflows a `u64`.
+///
+/// Worst-case performance: O(nA), for n denominations and an amount of A.
+/// Worst-case space complexity: O(A) auxiliary.
+pub fn count_ways(coins: &[u32], amount: u32) -> u64 {
+    let mut ways = vec![0u64; amount as usize + 1];
+    ways[0] = 1;
+    for &coin in coins.iter().filter(|&&coin| coin != 0) {
+        for a in coin as usize..ways.len() {
+            ways[a] =
+                ways[a].checked_add(ways[a - coin as usize]).expect("count_ways overflows u64");
+        }
+    }
+    ways[amount as usize]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_min_coins() {
+        assert_eq!(Some(3), min_coins(&[1, 2, 5], 11));
+        assert_eq!(None, min_coins(&[2], 3));
+        assert_eq!(Some(0), min_coins(&[1, 2, 5], 0));
+        assert_eq!(Some(0), min_coins(&[], 0));
+        assert_eq!(None, min_coins(&[], 1));
+        assert_eq!(None, min_coins(&[0], 1));
+
+        // Greedily taking the largest coin first would take 4 + 1 + 1.
+        assert_eq!(Some(2), min_coins(&[1, 3, 4], 6));
+        assert_eq!(Some(20), min_coins(&[186, 419, 83, 408], 6249));
+    }
+
+    #[test]
+    fn test_count_ways() {
+        assert_eq!(4, count_ways(&[1, 2, 5], 5));
+        assert_eq!(0, count_ways(&[2], 3));
+        assert_eq!(1, count_ways(&[10], 10));
+        assert_eq!(1, count_ways(&[1, 2, 5], 0));
+        assert_eq!(1, count_ways(&[], 0));
+        assert_eq!(0, count_ways(&[], 1));
+        assert_eq!(1, count_ways(&[0, 1], 3));
+        assert_eq!(292, count_ways(&[1, 5, 10, 25, 50], 100));
+    }
+}
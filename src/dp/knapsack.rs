@@ -0,0 +1,120 @@
+//! # 0/1 knapsack.
+
+/// Returns the maximum total value of items fitting in a knapsack of
+/// `capacity`, where item `i` weighs `weights[i]` and is worth `values[i]`,
+/// and each item is either taken whole or left.
+///
+/// Fill a table where entry `(i, w)` is the best value using only the first
+/// `i` items with capacity `w`. Each entry is the better of leaving item `i`,
+/// i.e. the entry above, or taking it if it fits, i.e. its value plus the
+/// entry above with its weight's less capacity. Each row only depends on the
+/// row above, so a single row is updated in place, from the highest capacity
+/// down so that each item is only counted once.
+///
+/// Panics if `weights` and `values` are of different lengths.
+///
+/// Worst-case performance: O(nW), for n items and a capacity of W.
+/// Worst-case space complexity: O(W) auxiliary.
+pub fn knapsack_01(weights: &[u32], values: &[u32], capacity: u32) -> u32 {
+    assert_eq!(weights.len(), values.len(), "weights and values must be the same length");
+
+    let mut best = vec![0; capacity as usize + 1];
+    for (&weight, &value) in weights.iter().zip(values) {
+        let weight = weight as usize;
+        for w in (weight..best.len()).rev() {
+            best[w] = best[w].max(best[w - weight] + value);
+        }
+    }
+    best[capacity as usize]
+}
+
+/// Returns the indices, ascending, of a set of items of the maximum total
+/// value fitting in a knapsack of `capacity`.
+///
+/// See [`knapsack_01`]. The whole table is kept, so that the items taken can
+/// be recovered by backtracking from the last entry: wherever an entry
+/// differs from the one above, its item was taken, so step up a row and left
+/// by its weight, otherwise just step up.
+///
+/// Panics if `weights` and `values` are of different lengths.
+///
+/// Worst-case performance: O(nW), for n items and a capacity of W.
+/// Worst-case space complexity: O(nW) auxiliary.
+pub fn knapsack_01_items(weights: &[u32], values: &[u32], capacity: u32) -> Vec<usize> {
+    assert_eq!(weights.len(), values.len(), "weights and values must be the same length");
+
+    let width = capacity as usize + 1;
+    let mut table = vec![0; (weights.len() + 1) * width];
+    for i in 1..=weights.len() {
+        let (weight, value) = (weights[i - 1] as usize, values[i - 1]);
+        for w in 0..width {
+            let leave = table[(i - 1) * width + w];
+            table[i * width + w] = if weight <= w {
+                leave.max(table[(i - 1) * width + w - weight] + value)
+            } else {
+                leave
+            };
+        }
+    }
+
+    let mut items = Vec::new();
+    let mut w = capacity as usize;
+    for i in (1..=weights.len()).rev() {
+        if table[i * width + w] != table[(i - 1) * width + w] {
+            items.push(i - 1);
+            w -= weights[i - 1] as usize;
+        }
+    }
+    items.reverse();
+    items
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_knapsack_01() {
+        let (weights, values) = (&[1, 3, 4, 5], &[1, 4, 5, 7]);
+        assert_eq!(9, knapsack_01(weights, values, 7));
+        assert_eq!(0, knapsack_01(weights, values, 0));
+        assert_eq!(17, knapsack_01(weights, values, 13));
+        assert_eq!(17, knapsack_01(weights, values, 100));
+
+        let (weights, values) = (&[10, 20, 30], &[60, 100, 120]);
+        assert_eq!(220, knapsack_01(weights, values, 50));
+
+        // Weightless items are always taken.
+        assert_eq!(5, knapsack_01(&[0, 0], &[2, 3], 0));
+        assert_eq!(0, knapsack_01(&[], &[], 10));
+    }
+
+    #[test]
+    fn test_knapsack_01_items() {
+        let (weights, values) = (&[1, 3, 4, 5], &[1, 4, 5, 7]);
+        assert_eq!(vec![1, 2], knapsack_01_items(weights, values, 7));
+        assert!(knapsack_01_items(weights, values, 0).is_empty());
+        assert_eq!(vec![0, 1, 2, 3], knapsack_01_items(weights, values, 13));
+
+        let (weights, values) = (&[10, 20, 30], &[60, 100, 120]);
+        assert_eq!(vec![1, 2], knapsack_01_items(weights, values, 50));
+
+        // The chosen items always fit and reach the optimum.
+        let weights: Vec<u32> = (0..20).map(|i| (i * 7) % 13 + 1).collect();
+        let values: Vec<u32> = (0..20).map(|i| (i * 11) % 17 + 1).collect();
+        for capacity in 0..60 {
+            let items = knapsack_01_items(&weights, &values, capacity);
+            assert!(items.iter().map(|&i| weights[i]).sum::<u32>() <= capacity);
+            assert_eq!(
+                knapsack_01(&weights, &values, capacity),
+                items.iter().map(|&i| values[i]).sum::<u32>()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_knapsack_01_mismatched_lengths() {
+        knapsack_01(&[1, 2], &[1], 5);
+    }
+}
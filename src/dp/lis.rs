@@ -0,0 +1,102 @@
+//! # longest increasing subsequence.
+
+/// Returns the length of the longest strictly increasing subsequence of
+/// `seq`, using patience sorting.
+///
+/// Iterate the sequence, maintaining for each length the smallest value that
+/// ends an increasing subsequence of that length, its "tail". The tails are
+/// strictly increasing, so binary search them for the first tail not less
+/// than the value. The value extends the subsequence ending just before it,
+/// so replaces that tail, or if every tail is less, extends the longest
+/// subsequence and becomes the tail of a new length.
+///
+/// Worst-case performance: O(n log n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn lis(seq: &[i32]) -> usize {
+    let mut tails: Vec<i32> = Vec::new();
+    for &x in seq {
+        let len = tails.partition_point(|&tail| tail < x);
+        if len == tails.len() {
+            tails.push(x);
+        } else {
+            tails[len] = x;
+        }
+    }
+    tails.len()
+}
+
+/// Returns a longest strictly increasing subsequence of `seq`.
+///
+/// See [`lis`]. Rather than the tail values, track the index of each tail,
+/// and link each value to the tail it extends, its predecessor. Then walk the
+/// links back from the tail of the longest subsequence.
+///
+/// If there are several longest increasing subsequences, one ending in the
+/// smallest possible value is returned.
+///
+/// Worst-case performance: O(n log n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn lis_sequence(seq: &[i32]) -> Vec<i32> {
+    // The index of each tail, and of each value's predecessor.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; seq.len()];
+    for (i, &x) in seq.iter().enumerate() {
+        let len = tails.partition_point(|&tail| seq[tail] < x);
+        prev[i] = len.checked_sub(1).map(|j| tails[j]);
+        if len == tails.len() {
+            tails.push(i);
+        } else {
+            tails[len] = i;
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        subsequence.push(seq[i]);
+        next = prev[i];
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns `true` if `sub` is strictly increasing and appears in `seq` in
+    /// order.
+    fn is_increasing_subsequence(sub: &[i32], seq: &[i32]) -> bool {
+        let mut rest = seq.iter();
+        sub.windows(2).all(|w| w[0] < w[1]) && sub.iter().all(|x| rest.any(|y| x == y))
+    }
+
+    #[test]
+    fn test_lis() {
+        assert_eq!(4, lis(&[10, 9, 2, 5, 3, 7, 101, 18]));
+        assert_eq!(4, lis(&[0, 1, 0, 3, 2, 3]));
+        assert_eq!(1, lis(&[7, 7, 7, 7]));
+        assert_eq!(1, lis(&[5, 4, 3, 2, 1]));
+        assert_eq!(5, lis(&[-2, -1, 0, 1, 2]));
+        assert_eq!(1, lis(&[42]));
+        assert_eq!(0, lis(&[]));
+    }
+
+    #[test]
+    fn test_lis_sequence() {
+        for seq in [
+            &[10, 9, 2, 5, 3, 7, 101, 18][..],
+            &[0, 1, 0, 3, 2, 3],
+            &[7, 7, 7, 7],
+            &[5, 4, 3, 2, 1],
+            &[3, 10, 2, 1, 20, 4, 6, 7],
+            &[],
+        ] {
+            let sub = lis_sequence(seq);
+            assert_eq!(lis(seq), sub.len());
+            assert!(is_increasing_subsequence(&sub, seq));
+        }
+        assert_eq!(vec![2, 3, 7, 18], lis_sequence(&[10, 9, 2, 5, 3, 7, 101, 18]));
+        assert_eq!(vec![1], lis_sequence(&[5, 4, 3, 2, 1]));
+    }
+}
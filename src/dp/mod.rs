@@ -0,0 +1,8 @@
+//! # dynamic programming algorithms.
+//!
+//! `dp` defines various algorithms solving problems by combining the solutions
+//! of overlapping subproblems.
+
+pub mod coin_change;
+pub mod knapsack;
+pub mod lis;
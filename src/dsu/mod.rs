@@ -0,0 +1,140 @@
+//! # disjoint-set data structures.
+//!
+//! `dsu` defines a disjoint-set, or union-find, data structure over element
+//! indices.
+
+/// A partition of the elements `0..n` into disjoint sets.
+///
+/// Each set is a tree of elements linked to their parent, with the root as the
+/// set's representative, so two elements are in the same set when they have
+/// the same root. Two optimisations keep the trees shallow:
+///
+/// - Path compression, where `find` links every element on the path it walks
+///   directly to the root.
+/// - Union by rank, where `union` links the root of the shorter tree under the
+///   root of the taller one, using rank as an upper bound on height.
+///
+/// Together they make each operation O(α(n)), the inverse Ackermann function,
+/// which is below 5 for any practical n.
+///
+/// Find: O(α(n)) amortised.
+/// Union: O(α(n)) amortised.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Creates `n` singleton sets, one per element `0..n`.
+    pub fn with_len(n: usize) -> Self {
+        Self { parent: (0..n).collect(), rank: vec![0; n], count: n }
+    }
+
+    /// Returns the representative of the set containing `x`.
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Compress the path by linking each element on it to the root.
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`.
+    ///
+    /// Returns `false` if they were already in the same set.
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // Link the lower ranked root under the higher ranked one. Only linking
+        // equal ranks makes the tree taller.
+        let (child, parent) = if self.rank[a] < self.rank[b] { (a, b) } else { (b, a) };
+        self.parent[child] = parent;
+        if self.rank[child] == self.rank[parent] {
+            self.rank[parent] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut set = DisjointSet::with_len(5);
+        assert_eq!(5, set.count());
+        assert!(!set.connected(0, 1));
+
+        assert!(set.union(0, 1));
+        assert_eq!(4, set.count());
+        assert!(set.union(1, 2));
+        assert_eq!(3, set.count());
+        assert!(set.union(2, 3));
+        assert_eq!(2, set.count());
+
+        // Already connected, so nothing changes.
+        assert!(!set.union(3, 0));
+        assert_eq!(2, set.count());
+
+        for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 3)] {
+            assert!(set.connected(a, b));
+        }
+        for a in 0..4 {
+            assert!(!set.connected(a, 4));
+        }
+        assert_eq!(4, set.find(4));
+    }
+
+    #[test]
+    fn test_disjoint_set_long_chain() {
+        let n = 10_000;
+        let mut set = DisjointSet::with_len(n);
+        for i in 1..n {
+            assert!(set.union(i - 1, i));
+        }
+        assert_eq!(1, set.count());
+        let root = set.find(0);
+        assert!((0..n).all(|i| set.find(i) == root));
+    }
+}
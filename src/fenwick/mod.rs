@@ -0,0 +1,148 @@
+//! # Fenwick tree data structures.
+//!
+//! `fenwick` defines a Fenwick tree, or binary indexed tree, for prefix sums
+//! over a list of integers.
+
+/// A list of `i64` values supporting point updates and prefix sums.
+///
+/// The tree is stored in a `Vec`, 1-indexed, where the node at index `i`
+/// holds the sum of the `i & -i` values ending at `i`, i.e. a block as long as
+/// the lowest set bit of `i`. A prefix sum adds up the blocks found by
+/// repeatedly clearing the lowest set bit of the index, and an update adds to
+/// every block containing the value, found by repeatedly adding the lowest
+/// set bit. Either way, at most one block per bit of the index is visited.
+///
+/// Add: O(log n).
+/// Prefix sum: O(log n).
+/// Range sum: O(log n).
+#[derive(Debug, Clone)]
+pub struct FenwickTree {
+    /// The block sums, with `tree[0]` unused.
+    tree: Vec<i64>,
+}
+
+impl FenwickTree {
+    /// Creates a tree of `n` zeros.
+    pub fn with_len(n: usize) -> Self {
+        Self { tree: vec![0; n + 1] }
+    }
+
+    /// Creates a tree of the values in `values`.
+    ///
+    /// Rather than adding the values one at a time in O(n log n), each block
+    /// sum is pushed up into the next block containing it, which takes O(n).
+    pub fn from_slice(values: &[i64]) -> Self {
+        let mut tree = vec![0; values.len() + 1];
+        tree[1..].copy_from_slice(values);
+        for i in 1..tree.len() {
+            let parent = i + lowest_bit(i);
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        Self { tree }
+    }
+
+    /// Adds `delta` to the value at `i`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn add(&mut self, i: usize, delta: i64) {
+        assert!(i < self.len(), "index {i} is out of bounds");
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += lowest_bit(i);
+        }
+    }
+
+    /// Returns the sum of the values at `0..=i`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn prefix_sum(&self, i: usize) -> i64 {
+        assert!(i < self.len(), "index {i} is out of bounds");
+        let mut sum = 0;
+        let mut i = i + 1;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= lowest_bit(i);
+        }
+        sum
+    }
+
+    /// Returns the sum of the values at `l..=r`.
+    ///
+    /// Panics if `l > r` or `r` is out of bounds.
+    pub fn range_sum(&self, l: usize, r: usize) -> i64 {
+        assert!(l <= r, "range {l}..={r} is reversed");
+        let before = if l == 0 { 0 } else { self.prefix_sum(l - 1) };
+        self.prefix_sum(r) - before
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Returns the lowest set bit of `i`, i.e. `i & -i` in two's complement.
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fenwick_tree() {
+        let mut tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(5, tree.len());
+        assert_eq!(9, tree.range_sum(1, 3));
+        assert_eq!(15, tree.prefix_sum(4));
+        assert_eq!(1, tree.prefix_sum(0));
+        assert_eq!(3, tree.range_sum(2, 2));
+
+        tree.add(2, 10);
+        assert_eq!(19, tree.range_sum(1, 3));
+        assert_eq!(25, tree.prefix_sum(4));
+        assert_eq!(3, tree.prefix_sum(1));
+        assert_eq!(9, tree.range_sum(3, 4));
+
+        tree.add(0, -1);
+        assert_eq!(0, tree.prefix_sum(0));
+    }
+
+    #[test]
+    fn test_fenwick_tree_from_slice() {
+        let values: Vec<i64> = (0..100).map(|i| (i * 37) % 11 - 5).collect();
+        let built = FenwickTree::from_slice(&values);
+        let mut added = FenwickTree::with_len(values.len());
+        for (i, &v) in values.iter().enumerate() {
+            added.add(i, v);
+        }
+
+        for i in 0..values.len() {
+            let expected: i64 = values[..=i].iter().sum();
+            assert_eq!(expected, built.prefix_sum(i));
+            assert_eq!(expected, added.prefix_sum(i));
+        }
+    }
+
+    #[test]
+    fn test_fenwick_tree_empty() {
+        let tree = FenwickTree::with_len(0);
+        assert!(tree.is_empty());
+        assert!(FenwickTree::from_slice(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_fenwick_tree_out_of_bounds() {
+        FenwickTree::with_len(3).prefix_sum(3);
+    }
+}
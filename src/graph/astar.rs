@@ -0,0 +1,197 @@
+//! # A* pathfinding.
+
+use crate::heap::BinaryHeap;
+
+/// A grid of cells, each either open or blocked, addressed by `(row, col)`.
+#[derive(Debug, Clone)]
+pub struct Grid {
+    blocked: Vec<bool>,
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid {
+    /// Creates a grid of `rows` by `cols` open cells.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { blocked: vec![false; rows * cols], rows, cols }
+    }
+
+    /// Blocks the cell at `cell`.
+    ///
+    /// Panics if `cell` is out of bounds.
+    pub fn block(&mut self, cell: (usize, usize)) {
+        let i = self.index(cell);
+        self.blocked[i] = true;
+    }
+
+    /// Returns `true` if the cell at `cell` is blocked.
+    ///
+    /// Panics if `cell` is out of bounds.
+    pub fn is_blocked(&self, cell: (usize, usize)) -> bool {
+        self.blocked[self.index(cell)]
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the index into `blocked` of `cell`.
+    fn index(&self, (row, col): (usize, usize)) -> usize {
+        assert!(row < self.rows && col < self.cols, "cell ({row}, {col}) is out of bounds");
+        row * self.cols + col
+    }
+
+    /// Returns the open cells above, below, left and right of `cell`.
+    fn neighbors(&self, (row, col): (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let up = row.checked_sub(1).map(|row| (row, col));
+        let down = (row + 1 < self.rows).then_some((row + 1, col));
+        let left = col.checked_sub(1).map(|col| (row, col));
+        let right = (col + 1 < self.cols).then_some((row, col + 1));
+        [up, down, left, right].into_iter().flatten().filter(|&cell| !self.is_blocked(cell))
+    }
+}
+
+/// Returns a shortest path of open cells from `start` to `goal`, inclusive,
+/// or `None` if the goal is unreachable, using A* search.
+///
+/// Moves are to the cell above, below, left or right, each costing 1, and
+/// not diagonally.
+///
+/// Like Dijkstra's algorithm, keep a frontier of cells in a min-heap, but
+/// order them by the cost so far plus an estimate of the cost remaining,
+/// rather than the cost so far alone. This steers the search towards the
+/// goal. The estimate is the Manhattan distance, which never overestimates
+/// the remaining cost of 4-directional moves, so the first time the goal is
+/// popped its path is a shortest one. Each cell records the cell it was
+/// reached from, so the path is recovered by walking back from the goal.
+///
+/// Panics if `start` or `goal` is out of bounds.
+///
+/// Worst-case performance: O(V log V), for a grid of V cells.
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn astar(
+    grid: &Grid,
+    start: (usize, usize),
+    goal: (usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+    if grid.is_blocked(start) || grid.is_blocked(goal) {
+        return None;
+    }
+
+    let estimate = |(row, col): (usize, usize)| row.abs_diff(goal.0) + col.abs_diff(goal.1);
+    // The cost of the cheapest path found to each cell, and the cell before it.
+    let mut cost: Vec<Option<usize>> = vec![None; grid.blocked.len()];
+    let mut came_from: Vec<Option<(usize, usize)>> = vec![None; grid.blocked.len()];
+    let mut frontier = BinaryHeap::new_min();
+    cost[grid.index(start)] = Some(0);
+    frontier.push((estimate(start), 0, start));
+
+    while let Some((_, c, cell)) = frontier.pop() {
+        if cell == goal {
+            let mut path = vec![goal];
+            while let Some(prev) = came_from[grid.index(*path.last()?)] {
+                path.push(prev);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        // Skip stale entries for cells since reached more cheaply.
+        if cost[grid.index(cell)].is_some_and(|best| c > best) {
+            continue;
+        }
+
+        for next in grid.neighbors(cell) {
+            let i = grid.index(next);
+            if cost[i].is_none_or(|best| c + 1 < best) {
+                cost[i] = Some(c + 1);
+                came_from[i] = Some(cell);
+                frontier.push((c + 1 + estimate(next), c + 1, next));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the grid drawn by `rows`, where `#` is a blocked cell.
+    fn grid(rows: &[&str]) -> Grid {
+        let mut grid = Grid::new(rows.len(), rows[0].len());
+        for (row, line) in rows.iter().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                if c == '#' {
+                    grid.block((row, col));
+                }
+            }
+        }
+        grid
+    }
+
+    /// Asserts `path` is a sequence of adjacent open cells.
+    fn assert_valid_path(grid: &Grid, path: &[(usize, usize)]) {
+        for &cell in path {
+            assert!(!grid.is_blocked(cell));
+        }
+        for w in path.windows(2) {
+            assert_eq!(1, w[0].0.abs_diff(w[1].0) + w[0].1.abs_diff(w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::new(2, 3);
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert!(!grid.is_blocked((1, 2)));
+        grid.block((1, 2));
+        assert!(grid.is_blocked((1, 2)));
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbors((0, 0)).collect::<Vec<_>>());
+        assert_eq!(vec![(0, 1)], grid.neighbors((0, 2)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_astar() {
+        // The wall forces a detour around one of its ends.
+        let grid = grid(&[
+            ".....", //
+            "..#..", //
+            "..#..", //
+            "..#..", //
+            ".....", //
+        ]);
+        let path = astar(&grid, (1, 0), (1, 4)).unwrap();
+        assert_eq!((1, 0), path[0]);
+        assert_eq!((1, 4), *path.last().unwrap());
+        assert_valid_path(&grid, &path);
+        // Up and over the wall, rather than straight across.
+        assert_eq!(7, path.len());
+
+        // Down and under the wall, from nearer its bottom end.
+        let path = astar(&grid, (3, 1), (3, 3)).unwrap();
+        assert_valid_path(&grid, &path);
+        assert_eq!(5, path.len());
+
+        assert_eq!(Some(vec![(2, 1)]), astar(&grid, (2, 1), (2, 1)));
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        // The top left is walled in.
+        let grid = grid(&[
+            "...#.", //
+            "...#.", //
+            "####.", //
+            ".....", //
+        ]);
+        assert_eq!(None, astar(&grid, (3, 0), (0, 0)));
+        assert_eq!(None, astar(&grid, (0, 0), (2, 0)));
+        assert!(astar(&grid, (3, 0), (0, 4)).is_some());
+    }
+}
@@ -0,0 +1,895 @@
+//! # graph data structures and algorithms.
+//!
+//! `graph` defines an adjacency list graph over node ids, and algorithms on
+//! it.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+pub mod astar;
+pub mod builder;
+pub mod mst;
+pub mod weighted;
+
+/// A directed or undirected graph over the nodes `0..n`, stored as adjacency
+/// lists.
+///
+/// Each node has a list of the nodes its edges lead to, in the order the
+/// edges were added. An undirected edge is stored as an edge in each
+/// direction.
+///
+/// Add edge: O(1) amortised.
+/// Neighbors: O(1).
+/// Space complexity: O(V + E).
+#[derive(Debug, Clone)]
+pub struct Graph {
+    adjacency: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+impl Graph {
+    /// Creates a directed graph with `n` nodes and no edges.
+    pub fn directed(n: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); n], directed: true }
+    }
+
+    /// Creates an undirected graph with `n` nodes and no edges.
+    pub fn undirected(n: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); n], directed: false }
+    }
+
+    /// Adds an edge from `u` to `v`, and from `v` to `u` if the graph is
+    /// undirected.
+    ///
+    /// Panics if `u` or `v` is out of bounds.
+    pub fn add_edge(&mut self, u: usize, v: usize) {
+        assert!(v < self.len(), "node {v} is out of bounds");
+        self.adjacency[u].push(v);
+        if !self.directed {
+            self.adjacency[v].push(u);
+        }
+    }
+
+    /// Returns the nodes the edges from `u` lead to.
+    ///
+    /// Panics if `u` is out of bounds.
+    pub fn neighbors(&self, u: usize) -> &[usize] {
+        &self.adjacency[u]
+    }
+
+    /// Returns `true` if the graph's edges are directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    /// Returns the nodes reachable from `start` in breadth-first order.
+    ///
+    /// Visit `start`, then all of its neighbors, then all of their unvisited
+    /// neighbors and so on, using a queue so that nodes are visited in order
+    /// of their distance, in edges, from `start`.
+    ///
+    /// Panics if `start` is out of bounds.
+    ///
+    /// Worst-case performance: O(V + E).
+    /// Worst-case space complexity: O(V) auxiliary.
+    pub fn bfs(&self, start: usize) -> Vec<usize> {
+        assert!(start < self.len(), "node {start} is out of bounds");
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+            for &v in &self.adjacency[u] {
+                // Mark on enqueue, so a node is never queued twice.
+                if !visited[v] {
+                    visited[v] = true;
+                    queue.push_back(v);
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns the nodes reachable from `start` in depth-first order.
+    ///
+    /// Visit `start`, then follow its first edge as deep as possible before
+    /// backtracking to follow the next. An explicit stack stands in for
+    /// recursion, so large graphs can't overflow the call stack. Neighbors are
+    /// pushed in reverse so they are popped, and visited, in the order the
+    /// edges were added, the same as the recursive formulation.
+    ///
+    /// Panics if `start` is out of bounds.
+    ///
+    /// Worst-case performance: O(V + E).
+    /// Worst-case space complexity: O(V + E) auxiliary.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        assert!(start < self.len(), "node {start} is out of bounds");
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(u) = stack.pop() {
+            // A node can be pushed by several neighbors before it's visited.
+            if visited[u] {
+                continue;
+            }
+            visited[u] = true;
+            order.push(u);
+            stack.extend(self.adjacency[u].iter().rev().filter(|&&v| !visited[v]));
+        }
+        order
+    }
+}
+
+/// The error returned when an algorithm that requires an acyclic graph finds a
+/// cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleError;
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle")
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Returns the nodes of a directed acyclic graph in topological order, i.e. so
+/// that for every edge `u -> v`, `u` comes before `v`, using Kahn's algorithm.
+///
+/// Count the incoming edges of every node. Nodes with none can go first, so
+/// queue them, and repeatedly take a node from the queue, append it to the
+/// order and remove its outgoing edges by decrementing its neighbors' counts,
+/// queueing any that reach zero. If the queue runs dry before every node is
+/// ordered, the remaining nodes all have incoming edges from each other, i.e.
+/// they form a cycle.
+///
+/// Every edge of an undirected graph is a cycle between its two nodes, so
+/// this only succeeds for undirected graphs without edges.
+///
+/// Returns [`CycleError`] if the graph contains a cycle.
+///
+/// Worst-case performance: O(V + E).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn topological_sort(graph: &Graph) -> Result<Vec<usize>, CycleError> {
+    let mut in_degree = vec![0usize; graph.len()];
+    for u in 0..graph.len() {
+        for &v in graph.neighbors(u) {
+            in_degree[v] += 1;
+        }
+    }
+
+    let mut queue: VecDeque<usize> = (0..graph.len()).filter(|&u| in_degree[u] == 0).collect();
+    let mut order = Vec::with_capacity(graph.len());
+    while let Some(u) = queue.pop_front() {
+        order.push(u);
+        for &v in graph.neighbors(u) {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+
+    if order.len() == graph.len() {
+        Ok(order)
+    } else {
+        Err(CycleError)
+    }
+}
+
+/// Returns the connected component of every node of an undirected graph, as
+/// ids from 0 numbering the components in order of their lowest node.
+///
+/// For each node not yet in a component, flood fill its component with a
+/// breadth-first search, labelling every node reached with a new id.
+///
+/// Panics if the graph is directed.
+///
+/// Worst-case performance: O(V + E).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn connected_components(graph: &Graph) -> Vec<usize> {
+    assert!(!graph.is_directed(), "graph must be undirected");
+    let mut component = vec![None; graph.len()];
+    let mut count = 0;
+    let mut queue = VecDeque::new();
+
+    for start in 0..graph.len() {
+        if component[start].is_some() {
+            continue;
+        }
+        component[start] = Some(count);
+        queue.push_back(start);
+        while let Some(u) = queue.pop_front() {
+            for &v in graph.neighbors(u) {
+                if component[v].is_none() {
+                    component[v] = Some(count);
+                    queue.push_back(v);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    // Safe to unwrap, every node was labelled by the time its turn came.
+    component.into_iter().map(Option::unwrap).collect()
+}
+
+/// Returns the number of connected components of an undirected graph.
+///
+/// See [`connected_components`].
+///
+/// Panics if the graph is directed.
+pub fn num_components(graph: &Graph) -> usize {
+    connected_components(graph).into_iter().max().map_or(0, |id| id + 1)
+}
+
+/// Returns `true` if the graph contains a cycle.
+///
+/// For a directed graph, run a depth-first search from every unvisited node,
+/// colouring nodes gray while they're on the search path and black once all
+/// their descendants are done. An edge to a gray node leads back to an
+/// ancestor on the path, closing a cycle.
+///
+/// For an undirected graph, every edge leads back the way it came, so a
+/// depth-first search instead ignores the edge to each node's parent, once,
+/// and any other edge to a visited node closes a cycle. Ignoring the parent
+/// only once means parallel edges, and self loops, count as cycles.
+///
+/// Both searches use an explicit stack rather than recursion, so large graphs
+/// can't overflow the call stack.
+///
+/// Worst-case performance: O(V + E).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn has_cycle(graph: &Graph) -> bool {
+    if graph.is_directed() {
+        has_directed_cycle(graph)
+    } else {
+        has_undirected_cycle(graph)
+    }
+}
+
+/// The search state of a node in [`has_directed_cycle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// Not yet visited.
+    White,
+    /// On the current search path.
+    Gray,
+    /// Visited, along with all of its descendants.
+    Black,
+}
+
+/// See [`has_cycle`].
+fn has_directed_cycle(graph: &Graph) -> bool {
+    let mut color = vec![Color::White; graph.len()];
+    for start in 0..graph.len() {
+        if color[start] != Color::White {
+            continue;
+        }
+
+        // The search path, with the index of each node's next edge to follow.
+        let mut stack = vec![(start, 0)];
+        color[start] = Color::Gray;
+        while let Some((u, next)) = stack.last_mut() {
+            let Some(&v) = graph.neighbors(*u).get(*next) else {
+                color[*u] = Color::Black;
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            match color[v] {
+                Color::Gray => return true,
+                Color::White => {
+                    color[v] = Color::Gray;
+                    stack.push((v, 0));
+                }
+                Color::Black => {}
+            }
+        }
+    }
+    false
+}
+
+/// See [`has_cycle`].
+fn has_undirected_cycle(graph: &Graph) -> bool {
+    let mut visited = vec![false; graph.len()];
+    for start in 0..graph.len() {
+        if visited[start] {
+            continue;
+        }
+
+        // The search path, with each node's parent, if its edge back to it is
+        // still to be ignored, and the index of its next edge to follow.
+        let mut stack = vec![(start, None, 0)];
+        visited[start] = true;
+        while let Some((u, parent, next)) = stack.last_mut() {
+            let Some(&v) = graph.neighbors(*u).get(*next) else {
+                stack.pop();
+                continue;
+            };
+            *next += 1;
+            if *parent == Some(v) {
+                *parent = None;
+            } else if visited[v] {
+                return true;
+            } else {
+                visited[v] = true;
+                let u = *u;
+                stack.push((v, Some(u), 0));
+            }
+        }
+    }
+    false
+}
+
+/// Returns the strongly connected components of a directed graph, i.e. the
+/// maximal sets of nodes that can all reach each other, using Tarjan's
+/// algorithm.
+///
+/// Run a depth-first search from every unvisited node, numbering nodes in the
+/// order they're visited and pushing them onto a stack. Each node's low-link
+/// is the lowest number reachable from its subtree using at most one edge
+/// back to a node still on the stack. A node whose low-link is its own number
+/// can't reach any earlier node, so it's the root of a component, made up of
+/// it and every node above it on the stack, which are popped off together.
+///
+/// The search uses an explicit stack rather than recursion, so large graphs
+/// can't overflow the call stack.
+///
+/// The components are returned in reverse topological order, i.e. every edge
+/// between two components points to an earlier one.
+///
+/// Worst-case performance: O(V + E).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn strongly_connected_components(graph: &Graph) -> Vec<Vec<usize>> {
+    let n = graph.len();
+    let mut number: Vec<Option<usize>> = vec![None; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut count = 0;
+
+    for start in 0..n {
+        if number[start].is_some() {
+            continue;
+        }
+
+        // The search path, with the index of each node's next edge to follow.
+        let mut path = vec![(start, 0)];
+        number[start] = Some(count);
+        low[start] = count;
+        count += 1;
+        stack.push(start);
+        on_stack[start] = true;
+
+        while let Some((u, next)) = path.last_mut() {
+            let u = *u;
+            if let Some(&v) = graph.neighbors(u).get(*next) {
+                *next += 1;
+                match number[v] {
+                    None => {
+                        number[v] = Some(count);
+                        low[v] = count;
+                        count += 1;
+                        stack.push(v);
+                        on_stack[v] = true;
+                        path.push((v, 0));
+                    }
+                    Some(number_v) if on_stack[v] => low[u] = low[u].min(number_v),
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // Every edge of `u` is done, so pass its low-link up to its parent.
+            path.pop();
+            if let Some(&(parent, _)) = path.last() {
+                low[parent] = low[parent].min(low[u]);
+            }
+
+            if Some(low[u]) == number[u] {
+                let mut component = Vec::new();
+                loop {
+                    // Safe to unwrap, `u` itself is still on the stack.
+                    let v = stack.pop().unwrap();
+                    on_stack[v] = false;
+                    component.push(v);
+                    if v == u {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Returns an Eulerian trail, i.e. a walk that follows every edge exactly
+/// once, as the nodes it visits, or `None` if there isn't one.
+///
+/// First check the degrees. Every time a trail passes through a node it enters
+/// and leaves it, so only its two ends can have unbalanced degrees. In an
+/// undirected graph, there must be 0 or 2 nodes with odd degree, and the trail
+/// starts at one of them, if any. In a directed graph, every node's in-degree
+/// must equal its out-degree, except that the start may have one extra out
+/// edge and the end one extra in edge.
+///
+/// Then use Hierholzer's algorithm. Walk from the start along unused edges
+/// until stuck, which can only happen back at the start, or at the end of the
+/// trail. Backtrack along the walk, emitting each node once it has no unused
+/// edges left, and whenever a node still has unused edges, walk a detour from
+/// it first. The emitted nodes, reversed, are the trail with every detour
+/// spliced in. If some edges were never reached, they're in another component
+/// and there's no trail.
+///
+/// If the trail starts and ends at the same node, it's an Eulerian circuit. A
+/// graph with no edges has the empty trail.
+///
+/// Worst-case performance: O(V + E).
+/// Worst-case space complexity: O(V + E) auxiliary.
+pub fn eulerian_path(graph: &Graph) -> Option<Vec<usize>> {
+    let n = graph.len();
+
+    // The edges of each node, by id and far end, with each undirected edge
+    // given a single id shared by both of its directions.
+    let mut edges: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    let mut num_edges = 0;
+    for u in 0..n {
+        // A self loop is listed twice in a row, once per direction, so only
+        // every other listing is a new edge.
+        let mut repeat = false;
+        for &v in graph.neighbors(u) {
+            if graph.is_directed() {
+                edges[u].push((num_edges, v));
+                num_edges += 1;
+            } else if u < v {
+                edges[u].push((num_edges, v));
+                edges[v].push((num_edges, u));
+                num_edges += 1;
+            } else if u == v {
+                if !repeat {
+                    edges[u].push((num_edges, u));
+                    num_edges += 1;
+                }
+                repeat = !repeat;
+            }
+        }
+    }
+    if num_edges == 0 {
+        return Some(Vec::new());
+    }
+
+    let start = eulerian_start(graph)?;
+
+    // The index of each node's next edge to try.
+    let mut next = vec![0; n];
+    let mut used = vec![false; num_edges];
+    let mut walk = vec![start];
+    let mut trail = Vec::with_capacity(num_edges + 1);
+    while let Some(&u) = walk.last() {
+        while next[u] < edges[u].len() && used[edges[u][next[u]].0] {
+            next[u] += 1;
+        }
+        if let Some(&(edge, v)) = edges[u].get(next[u]) {
+            used[edge] = true;
+            walk.push(v);
+        } else {
+            trail.push(u);
+            walk.pop();
+        }
+    }
+
+    if trail.len() != num_edges + 1 {
+        return None;
+    }
+    trail.reverse();
+    Some(trail)
+}
+
+/// Returns the node an Eulerian trail must start from, if the degrees allow
+/// one, assuming the graph has edges.
+///
+/// See [`eulerian_path`].
+fn eulerian_start(graph: &Graph) -> Option<usize> {
+    let n = graph.len();
+    // Safe to unwrap, the graph has an edge so some node has a neighbor.
+    let any = (0..n).find(|&u| !graph.neighbors(u).is_empty()).unwrap();
+
+    if !graph.is_directed() {
+        // A self loop is listed twice, so it adds 2 to the degree, as it should.
+        let odd: Vec<usize> = (0..n).filter(|&u| graph.neighbors(u).len() % 2 == 1).collect();
+        return match odd[..] {
+            [] => Some(any),
+            [start, _] => Some(start),
+            _ => None,
+        };
+    }
+
+    // Each node's out-degree less its in-degree.
+    let mut balance = vec![0i64; n];
+    for u in 0..n {
+        for &v in graph.neighbors(u) {
+            balance[u] += 1;
+            balance[v] -= 1;
+        }
+    }
+    let mut start = None;
+    let mut ends = 0;
+    for (u, &b) in balance.iter().enumerate() {
+        match b {
+            0 => {}
+            1 if start.is_none() => start = Some(u),
+            -1 if ends == 0 => ends += 1,
+            _ => return None,
+        }
+    }
+    match (start, ends) {
+        (None, 0) => Some(any),
+        (Some(start), 1) => Some(start),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the undirected graph:
+    ///
+    /// ```text
+    /// 0 - 1 - 3
+    /// |   |
+    /// 2 - 4   5 - 6
+    /// ```
+    fn sample_graph() -> Graph {
+        let mut graph = Graph::undirected(7);
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 4), (5, 6)] {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = sample_graph();
+        assert_eq!(7, graph.len());
+        assert!(!graph.is_directed());
+        assert_eq!(&[0, 3, 4], graph.neighbors(1));
+
+        let mut graph = Graph::directed(2);
+        graph.add_edge(0, 1);
+        assert_eq!(&[1], graph.neighbors(0));
+        assert!(graph.neighbors(1).is_empty());
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = sample_graph();
+        // Layer by layer: 0, then 1 and 2, then 3 and 4.
+        assert_eq!(vec![0, 1, 2, 3, 4], graph.bfs(0));
+        assert_eq!(vec![3, 1, 0, 4, 2], graph.bfs(3));
+        assert_eq!(vec![5, 6], graph.bfs(5));
+
+        let mut graph = Graph::directed(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(1, 2);
+        assert_eq!(vec![1, 2], graph.bfs(1));
+    }
+
+    #[test]
+    fn test_dfs() {
+        let graph = sample_graph();
+        assert_eq!(vec![0, 1, 3, 4, 2], graph.dfs(0));
+        assert_eq!(vec![5, 6], graph.dfs(5));
+
+        let mut visited = graph.dfs(4);
+        visited.sort();
+        assert_eq!(vec![0, 1, 2, 3, 4], visited);
+
+        // A long path would overflow a recursive DFS.
+        let n = 100_000;
+        let mut graph = Graph::directed(n);
+        for u in 1..n {
+            graph.add_edge(u - 1, u);
+        }
+        assert!(graph.dfs(0).into_iter().eq(0..n));
+    }
+
+    /// Asserts `order` is a permutation of the nodes of `graph` with every edge
+    /// pointing forwards.
+    fn assert_topological(graph: &Graph, order: &[usize]) {
+        let mut position = vec![None; graph.len()];
+        for (i, &u) in order.iter().enumerate() {
+            assert_eq!(None, position[u]);
+            position[u] = Some(i);
+        }
+        for u in 0..graph.len() {
+            for &v in graph.neighbors(u) {
+                assert!(position[u] < position[v], "edge {u} -> {v} points backwards");
+            }
+        }
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        // Getting dressed: 0 underwear, 1 trousers, 2 belt, 3 shirt, 4 tie,
+        // 5 jacket, 6 socks, 7 shoes.
+        let mut graph = Graph::directed(8);
+        for (u, v) in [(0, 1), (0, 7), (1, 2), (1, 7), (2, 5), (3, 2), (3, 4), (4, 5), (6, 7)] {
+            graph.add_edge(u, v);
+        }
+        let order = topological_sort(&graph).unwrap();
+        assert_topological(&graph, &order);
+
+        // No edges, so any order is valid.
+        assert_eq!(Ok(vec![0, 1, 2]), topological_sort(&Graph::directed(3)));
+        assert_eq!(Ok(vec![]), topological_sort(&Graph::directed(0)));
+    }
+
+    #[test]
+    fn test_topological_sort_cycle() {
+        let mut graph = Graph::directed(4);
+        for (u, v) in [(0, 1), (1, 2), (2, 3), (3, 1)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(Err(CycleError), topological_sort(&graph));
+
+        let mut graph = Graph::directed(1);
+        graph.add_edge(0, 0);
+        assert_eq!(Err(CycleError), topological_sort(&graph));
+
+        let mut graph = Graph::undirected(2);
+        graph.add_edge(0, 1);
+        assert_eq!(Err(CycleError), topological_sort(&graph));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        assert_eq!(vec![0, 0, 0, 0, 0, 1, 1], connected_components(&sample_graph()));
+        assert_eq!(2, num_components(&sample_graph()));
+
+        // Two disjoint triangles, interleaved, and an isolated node.
+        let mut graph = Graph::undirected(7);
+        for (u, v) in [(0, 2), (2, 4), (4, 0), (1, 3), (3, 5), (5, 1)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(vec![0, 1, 0, 1, 0, 1, 2], connected_components(&graph));
+        assert_eq!(3, num_components(&graph));
+
+        let graph = Graph::undirected(0);
+        assert!(connected_components(&graph).is_empty());
+        assert_eq!(0, num_components(&graph));
+    }
+
+    #[test]
+    fn test_has_cycle_directed() {
+        // A DAG, where 0 -> 3 is reachable two ways but isn't a cycle.
+        let mut graph = Graph::directed(4);
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        assert!(!has_cycle(&graph));
+
+        // The back edge 3 -> 0 closes a cycle.
+        graph.add_edge(3, 0);
+        assert!(has_cycle(&graph));
+
+        let mut graph = Graph::directed(1);
+        graph.add_edge(0, 0);
+        assert!(has_cycle(&graph));
+
+        assert!(!has_cycle(&Graph::directed(3)));
+    }
+
+    #[test]
+    fn test_has_cycle_undirected() {
+        // A tree.
+        let mut graph = Graph::undirected(5);
+        for (u, v) in [(0, 1), (0, 2), (1, 3), (1, 4)] {
+            graph.add_edge(u, v);
+        }
+        assert!(!has_cycle(&graph));
+
+        // 0 - 1 - 4 - 2 - 0.
+        assert!(has_cycle(&sample_graph()));
+
+        // Parallel edges are a cycle of length 2.
+        let mut graph = Graph::undirected(2);
+        graph.add_edge(0, 1);
+        assert!(!has_cycle(&graph));
+        graph.add_edge(1, 0);
+        assert!(has_cycle(&graph));
+
+        let mut graph = Graph::undirected(1);
+        graph.add_edge(0, 0);
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn test_has_cycle_long_path() {
+        // A long path would overflow a recursive search.
+        let n = 100_000;
+        let mut directed = Graph::directed(n);
+        let mut undirected = Graph::undirected(n);
+        for u in 1..n {
+            directed.add_edge(u - 1, u);
+            undirected.add_edge(u - 1, u);
+        }
+        assert!(!has_cycle(&directed));
+        assert!(!has_cycle(&undirected));
+    }
+
+    /// Returns `components` with each component, and the list of them, sorted.
+    fn sorted_components(mut components: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        for component in &mut components {
+            component.sort();
+        }
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // The cycle 0 -> 1 -> 2 -> 0, leading to 3 and the cycle 4 <-> 5, with 6
+        // on its own.
+        let mut graph = Graph::directed(7);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 4)] {
+            graph.add_edge(u, v);
+        }
+        let components = strongly_connected_components(&graph);
+        assert_eq!(
+            vec![vec![0, 1, 2], vec![3], vec![4, 5], vec![6]],
+            sorted_components(components.clone())
+        );
+
+        // Edges between components point to earlier ones.
+        let mut position = vec![0; graph.len()];
+        for (i, component) in components.iter().enumerate() {
+            for &u in component {
+                position[u] = i;
+            }
+        }
+        for u in 0..graph.len() {
+            for &v in graph.neighbors(u) {
+                assert!(position[v] <= position[u]);
+            }
+        }
+
+        assert!(strongly_connected_components(&Graph::directed(0)).is_empty());
+    }
+
+    #[test]
+    fn test_strongly_connected_components_long_cycle() {
+        // A long cycle would overflow a recursive search.
+        let n = 100_000;
+        let mut graph = Graph::directed(n);
+        for u in 0..n {
+            graph.add_edge(u, (u + 1) % n);
+        }
+        let components = strongly_connected_components(&graph);
+        assert_eq!(1, components.len());
+        assert_eq!(n, components[0].len());
+    }
+
+    /// Asserts that `trail` is a walk in `graph` that uses each of `edges`,
+    /// the edges the graph was built from, exactly once.
+    fn assert_eulerian(graph: &Graph, edges: &[(usize, usize)], trail: &[usize]) {
+        assert_eq!(edges.len() + 1, trail.len());
+
+        let normalize = |(u, v): (usize, usize)| {
+            if graph.is_directed() {
+                (u, v)
+            } else {
+                (u.min(v), u.max(v))
+            }
+        };
+        let mut expected: Vec<(usize, usize)> = edges.iter().copied().map(normalize).collect();
+        let mut walked: Vec<(usize, usize)> =
+            trail.windows(2).map(|w| normalize((w[0], w[1]))).collect();
+        expected.sort();
+        walked.sort();
+        assert_eq!(expected, walked);
+    }
+
+    #[test]
+    fn test_eulerian_path_circuit() {
+        // Two triangles sharing node 2, so every degree is even.
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)];
+        let mut graph = Graph::undirected(5);
+        for (u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        let trail = eulerian_path(&graph).unwrap();
+        assert_eulerian(&graph, &edges, &trail);
+        assert_eq!(trail.first(), trail.last());
+
+        // The same, directed around each triangle.
+        let mut graph = Graph::directed(5);
+        for (u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        let trail = eulerian_path(&graph).unwrap();
+        assert_eulerian(&graph, &edges, &trail);
+        assert_eq!(trail.first(), trail.last());
+
+        // Self loops and parallel edges are each followed once.
+        let edges = [(0, 0), (0, 1), (1, 0), (1, 1)];
+        let mut graph = Graph::undirected(2);
+        for (u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        let trail = eulerian_path(&graph).unwrap();
+        assert_eulerian(&graph, &edges, &trail);
+    }
+
+    #[test]
+    fn test_eulerian_path_not_circuit() {
+        // The house shape, whose two bottom corners have odd degree.
+        //
+        //     4
+        //    / \
+        //   2 - 3
+        //   | X |
+        //   0 - 1
+        let edges = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4)];
+        let mut graph = Graph::undirected(5);
+        for (u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        let trail = eulerian_path(&graph).unwrap();
+        assert_eulerian(&graph, &edges, &trail);
+        assert_ne!(trail.first(), trail.last());
+        assert!([0, 1].contains(&trail[0]));
+
+        // A directed trail must start at the node with an extra out edge.
+        let edges = [(1, 0), (0, 2), (2, 1), (1, 3)];
+        let mut graph = Graph::directed(4);
+        for (u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        let trail = eulerian_path(&graph).unwrap();
+        assert_eq!(vec![1, 0, 2, 1, 3], trail);
+    }
+
+    #[test]
+    fn test_eulerian_path_none() {
+        // Four nodes of odd degree.
+        let mut graph = Graph::undirected(4);
+        for (u, v) in [(0, 1), (0, 2), (0, 3)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(None, eulerian_path(&graph));
+
+        // Balanced degrees, but in two components.
+        let mut graph = Graph::undirected(6);
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+            graph.add_edge(u, v);
+        }
+        assert_eq!(None, eulerian_path(&graph));
+
+        // Two nodes with an extra out edge.
+        let mut graph = Graph::directed(3);
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 1);
+        assert_eq!(None, eulerian_path(&graph));
+
+        // No edges at all need no trail.
+        assert_eq!(Some(Vec::new()), eulerian_path(&Graph::undirected(3)));
+        assert_eq!(Some(Vec::new()), eulerian_path(&Graph::directed(0)));
+    }
+}
@@ -0,0 +1,153 @@
+//! # minimum spanning trees.
+
+use crate::dsu::DisjointSet;
+use crate::graph::weighted::WeightedGraph;
+use crate::heap::BinaryHeap;
+use crate::sort::merge_sort_by_key;
+
+/// Returns the total weight and edges of a minimum spanning tree of the
+/// undirected graph over the nodes `0..n` with the `(u, v, weight)` edges
+/// `edges`, using Kruskal's algorithm.
+///
+/// Sort the edges by weight, then take each edge in turn unless it would
+/// close a cycle, i.e. unless its nodes are already connected by the edges
+/// taken so far. A [`DisjointSet`] of the nodes tracks which are connected.
+/// The lightest edge between any two groups of connected nodes is always
+/// part of some minimum spanning tree, so every edge taken is safe.
+///
+/// If the graph is disconnected, no spanning tree exists, and the result is
+/// instead a minimum spanning forest, one tree per connected component, with
+/// fewer than `n - 1` edges.
+///
+/// Edges of equal weight are taken in the order given, since the sort is
+/// stable.
+///
+/// Panics if an edge's node is out of bounds, or the total weight overflows
+/// `u64`.
+///
+/// Worst-case performance: O(E log E).
+/// Worst-case space complexity: O(V + E) auxiliary.
+pub fn kruskal(n: usize, edges: &[(usize, usize, u64)]) -> (u64, Vec<(usize, usize, u64)>) {
+    let mut sorted = edges.to_vec();
+    merge_sort_by_key(&mut sorted, |&(_, _, weight)| weight);
+
+    let mut components = DisjointSet::with_len(n);
+    let mut total: u64 = 0;
+    let mut tree = Vec::with_capacity(n.saturating_sub(1));
+    for (u, v, weight) in sorted {
+        if components.union(u, v) {
+            total = total.checked_add(weight).expect("total weight overflows u64");
+            tree.push((u, v, weight));
+        }
+    }
+    (total, tree)
+}
+
+/// Returns the total weight of a minimum spanning tree of the undirected
+/// `graph`, grown from `start`, using Prim's algorithm.
+///
+/// Grow the tree one node at a time, keeping a frontier of the edges leaving
+/// it in a min-heap. Repeatedly pop the lightest edge, and if it leads to a
+/// node not yet in the tree, add the node and push its edges. Like Kruskal's,
+/// the lightest edge leaving the tree is always part of some minimum spanning
+/// tree. Edges to nodes that joined the tree after they were pushed are
+/// skipped when popped.
+///
+/// If the graph is disconnected, only the nodes reachable from `start` are
+/// spanned, so the result is the weight of the minimum spanning tree of
+/// `start`'s connected component.
+///
+/// Panics if `start` is out of bounds, or the total weight overflows `u64`.
+///
+/// Worst-case performance: O(E log E).
+/// Worst-case space complexity: O(V + E) auxiliary.
+pub fn prim(graph: &WeightedGraph, start: usize) -> u64 {
+    let mut in_tree = vec![false; graph.len()];
+    let mut frontier = BinaryHeap::new_min();
+    let mut total: u64 = 0;
+    frontier.push((0, start));
+
+    while let Some((weight, u)) = frontier.pop() {
+        if in_tree[u] {
+            continue;
+        }
+        in_tree[u] = true;
+        total = total.checked_add(weight).expect("total weight overflows u64");
+        for &(v, weight) in graph.neighbors(u) {
+            if !in_tree[v] {
+                frontier.push((weight, v));
+            }
+        }
+    }
+    total
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A connected graph of 7 nodes, with a minimum spanning tree of weight 39.
+    const EDGES: [(usize, usize, u64); 11] = [
+        (0, 1, 7),
+        (0, 3, 5),
+        (1, 2, 8),
+        (1, 3, 9),
+        (1, 4, 7),
+        (2, 4, 5),
+        (3, 4, 15),
+        (3, 5, 6),
+        (4, 5, 8),
+        (4, 6, 9),
+        (5, 6, 11),
+    ];
+
+    #[test]
+    fn test_kruskal() {
+        let (total, tree) = kruskal(7, &EDGES);
+        assert_eq!(39, total);
+        assert_eq!(vec![(0, 3, 5), (2, 4, 5), (3, 5, 6), (0, 1, 7), (1, 4, 7), (4, 6, 9)], tree);
+
+        // A spanning tree connects every node.
+        let mut components = DisjointSet::with_len(7);
+        for &(u, v, _) in &tree {
+            components.union(u, v);
+        }
+        assert_eq!(1, components.count());
+    }
+
+    #[test]
+    fn test_kruskal_forest() {
+        // Two triangles and an isolated node make a forest of three trees.
+        let edges = [(0, 1, 1), (1, 2, 2), (0, 2, 3), (3, 4, 4), (4, 5, 5), (3, 5, 6)];
+        let (total, tree) = kruskal(7, &edges);
+        assert_eq!(12, total);
+        assert_eq!(vec![(0, 1, 1), (1, 2, 2), (3, 4, 4), (4, 5, 5)], tree);
+
+        assert_eq!((0, Vec::new()), kruskal(3, &[]));
+        assert_eq!((0, Vec::new()), kruskal(0, &[]));
+    }
+
+    #[test]
+    fn test_prim() {
+        let mut graph = WeightedGraph::undirected(7);
+        for (u, v, w) in EDGES {
+            graph.add_edge(u, v, w);
+        }
+        let (total, _) = kruskal(7, &EDGES);
+        for start in 0..7 {
+            assert_eq!(total, prim(&graph, start));
+        }
+    }
+
+    #[test]
+    fn test_prim_disconnected() {
+        // Two triangles and an isolated node, each spanned separately.
+        let mut graph = WeightedGraph::undirected(7);
+        for (u, v, w) in [(0, 1, 1), (1, 2, 2), (0, 2, 3), (3, 4, 4), (4, 5, 5), (3, 5, 6)] {
+            graph.add_edge(u, v, w);
+        }
+        assert_eq!(3, prim(&graph, 0));
+        assert_eq!(9, prim(&graph, 5));
+        assert_eq!(0, prim(&graph, 6));
+    }
+}
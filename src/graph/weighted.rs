@@ -0,0 +1,347 @@
+//! # weighted graphs.
+
+use std::fmt;
+
+use crate::heap::BinaryHeap;
+
+/// A directed or undirected graph over the nodes `0..n` with weighted edges,
+/// stored as adjacency lists.
+///
+/// Weights are unsigned, which is what algorithms such as [`dijkstra`]
+/// require.
+///
+/// Add edge: O(1) amortised.
+/// Neighbors: O(1).
+/// Space complexity: O(V + E).
+#[derive(Debug, Clone)]
+pub struct WeightedGraph {
+    adjacency: Vec<Vec<(usize, u64)>>,
+    directed: bool,
+}
+
+impl WeightedGraph {
+    /// Creates a directed graph with `n` nodes and no edges.
+    pub fn directed(n: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); n], directed: true }
+    }
+
+    /// Creates an undirected graph with `n` nodes and no edges.
+    pub fn undirected(n: usize) -> Self {
+        Self { adjacency: vec![Vec::new(); n], directed: false }
+    }
+
+    /// Adds an edge of `weight` from `u` to `v`, and from `v` to `u` if the
+    /// graph is undirected.
+    ///
+    /// Panics if `u` or `v` is out of bounds.
+    pub fn add_edge(&mut self, u: usize, v: usize, weight: u64) {
+        assert!(v < self.len(), "node {v} is out of bounds");
+        self.adjacency[u].push((v, weight));
+        if !self.directed {
+            self.adjacency[v].push((u, weight));
+        }
+    }
+
+    /// Returns the `(node, weight)` pairs of the edges from `u`.
+    ///
+    /// Panics if `u` is out of bounds.
+    pub fn neighbors(&self, u: usize) -> &[(usize, u64)] {
+        &self.adjacency[u]
+    }
+
+    /// Returns `true` if the graph's edges are directed.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Returns the number of nodes.
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Returns `true` if the graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+}
+
+/// Returns the shortest distance from `src` to every node, or `None` for nodes
+/// that are unreachable, using Dijkstra's algorithm.
+///
+/// Keep a frontier of tentative distances in a min-heap, starting with `src`
+/// at distance 0. Repeatedly pop the closest node, which is then settled at
+/// its shortest distance, and relax its edges, pushing any neighbor whose
+/// distance improves. A node can be pushed several times before it's settled,
+/// so stale entries are skipped when popped.
+///
+/// Settling the closest node first is only correct when no edge can shorten
+/// the path to an already settled node, i.e. when all weights are
+/// non-negative, which [`WeightedGraph`]'s unsigned weights guarantee. For
+/// negative weights, see [`bellman_ford`].
+///
+/// Panics if `src` is out of bounds, or a distance overflows `u64`.
+///
+/// Worst-case performance: O((V + E) log V).
+/// Worst-case space complexity: O(V + E) auxiliary.
+pub fn dijkstra(graph: &WeightedGraph, src: usize) -> Vec<Option<u64>> {
+    let mut dist = vec![None; graph.len()];
+    let mut frontier = BinaryHeap::new_min();
+    dist[src] = Some(0);
+    frontier.push((0u64, src));
+
+    while let Some((d, u)) = frontier.pop() {
+        // Skip stale entries for nodes already settled at a shorter distance.
+        if dist[u].is_some_and(|best| d > best) {
+            continue;
+        }
+        for &(v, weight) in graph.neighbors(u) {
+            let candidate = d.checked_add(weight).expect("distance overflows u64");
+            if dist[v].is_none_or(|best| candidate < best) {
+                dist[v] = Some(candidate);
+                frontier.push((candidate, v));
+            }
+        }
+    }
+    dist
+}
+
+/// The error returned when a shortest paths algorithm finds a cycle of negative
+/// total weight, around which paths can be made arbitrarily short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeCycle;
+
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a negative cycle")
+    }
+}
+
+impl std::error::Error for NegativeCycle {}
+
+/// Replaces the edge weights of `matrix` with the shortest distance between
+/// every pair of nodes, using the Floyd-Warshall algorithm.
+///
+/// `matrix[u][v]` is the weight of the edge from `u` to `v`, or `None` if
+/// there is none, and afterwards is the shortest distance from `u` to `v`, or
+/// `None` if `v` is unreachable from `u`. Every node is at distance 0 from
+/// itself, unless on a negative cycle, so a `None` diagonal entry is treated
+/// as 0. Weights may be negative.
+///
+/// For each node `k` in turn, allow paths to pass through `k`: the shortest
+/// path from `u` to `v` is then either the best found so far, or the best
+/// from `u` to `k` followed by the best from `k` to `v`. Once every node has
+/// been allowed, every path has been considered.
+///
+/// Returns [`NegativeCycle`] as soon as any node reaches a negative distance
+/// from itself, in which case `matrix` is left partially updated. Distances
+/// through a negative cycle grow exponentially with each node allowed, so
+/// stopping early keeps them from overflowing.
+///
+/// Panics if `matrix` isn't square, or a shortest distance overflows `i64`.
+///
+/// Worst-case performance: O(V^3).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn floyd_warshall(matrix: &mut [Vec<Option<i64>>]) -> Result<(), NegativeCycle> {
+    let n = matrix.len();
+    assert!(matrix.iter().all(|row| row.len() == n), "matrix must be square");
+
+    for (u, row) in matrix.iter_mut().enumerate() {
+        row[u] = Some(row[u].map_or(0, |w| w.min(0)));
+    }
+
+    for k in 0..n {
+        // Copy out row `k`, so the other rows can be updated while reading it.
+        let via_k = matrix[k].clone();
+        for (u, row) in matrix.iter_mut().enumerate() {
+            let Some(to_k) = row[k] else {
+                continue;
+            };
+            for (best, &from_k) in row.iter_mut().zip(&via_k) {
+                let Some(from_k) = from_k else {
+                    continue;
+                };
+                let candidate = to_k.checked_add(from_k).expect("distance overflows i64");
+                if best.is_none_or(|best| candidate < best) {
+                    *best = Some(candidate);
+                }
+            }
+            // Safe to unwrap, the diagonal was filled in above.
+            if row[u].unwrap() < 0 {
+                return Err(NegativeCycle);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the shortest distance from `src` to every node of the directed
+/// graph over the nodes `0..n` with the `(u, v, weight)` edges `edges`, or
+/// `None` for nodes that are unreachable, using the Bellman-Ford algorithm.
+///
+/// Relax every edge, i.e. shorten the distance to its end if going through it
+/// is shorter, V - 1 times. A shortest path without cycles has at most V - 1
+/// edges, and the `i`th pass settles every shortest path of `i` edges, so
+/// afterwards every distance is final. Stop early if a pass changes nothing.
+///
+/// Unlike [`dijkstra`], weights may be negative, at the cost of relaxing every
+/// edge per pass rather than each settled node's edges once.
+///
+/// Returns [`NegativeCycle`] if a negative cycle is reachable from `src`,
+/// detected by one more pass still finding an edge to relax.
+///
+/// Panics if `src` or an edge's node is out of bounds, or a distance
+/// overflows `i64`.
+///
+/// Worst-case performance: O(VE).
+/// Best-case performance: O(E).
+/// Worst-case space complexity: O(V) auxiliary.
+pub fn bellman_ford(
+    edges: &[(usize, usize, i64)],
+    n: usize,
+    src: usize,
+) -> Result<Vec<Option<i64>>, NegativeCycle> {
+    let mut dist = vec![None; n];
+    dist[src] = Some(0);
+
+    // Relaxes every edge, returning `true` if any distance shortened.
+    let relax = |dist: &mut [Option<i64>]| {
+        let mut changed = false;
+        for &(u, v, weight) in edges {
+            let Some(d) = dist[u] else {
+                continue;
+            };
+            let candidate = d.checked_add(weight).expect("distance overflows i64");
+            if dist[v].is_none_or(|best| candidate < best) {
+                dist[v] = Some(candidate);
+                changed = true;
+            }
+        }
+        changed
+    };
+
+    for _ in 1..n {
+        if !relax(&mut dist) {
+            return Ok(dist);
+        }
+    }
+    if relax(&mut dist) {
+        return Err(NegativeCycle);
+    }
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_graph() {
+        let mut graph = WeightedGraph::undirected(3);
+        graph.add_edge(0, 1, 5);
+        graph.add_edge(1, 2, 7);
+        assert_eq!(3, graph.len());
+        assert_eq!(&[(0, 5), (2, 7)], graph.neighbors(1));
+
+        let mut graph = WeightedGraph::directed(2);
+        graph.add_edge(0, 1, 5);
+        assert!(graph.is_directed());
+        assert!(graph.neighbors(1).is_empty());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // The direct edge 0 -> 1 is longer than going through 2, and node 4 is
+        // isolated.
+        let mut graph = WeightedGraph::directed(5);
+        for (u, v, w) in [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)] {
+            graph.add_edge(u, v, w);
+        }
+        assert_eq!(vec![Some(0), Some(3), Some(1), Some(4), None], dijkstra(&graph, 0));
+        assert_eq!(vec![None, Some(0), None, Some(1), None], dijkstra(&graph, 1));
+        assert_eq!(vec![None, None, None, None, Some(0)], dijkstra(&graph, 4));
+
+        let mut graph = WeightedGraph::undirected(4);
+        for (u, v, w) in [(0, 1, 7), (1, 2, 0), (2, 3, 2), (0, 3, 10)] {
+            graph.add_edge(u, v, w);
+        }
+        assert_eq!(vec![Some(9), Some(2), Some(2), Some(0)], dijkstra(&graph, 3));
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        // The direct edge 0 -> 2 is longer than going through 1, whose edge to
+        // 2 is negative, and node 3 can't reach the others.
+        let mut matrix = vec![
+            vec![None, Some(4), Some(5), None],
+            vec![None, None, Some(-2), None],
+            vec![Some(1), None, None, Some(3)],
+            vec![None, None, None, None],
+        ];
+        assert_eq!(Ok(()), floyd_warshall(&mut matrix));
+        assert_eq!(
+            vec![
+                vec![Some(0), Some(4), Some(2), Some(5)],
+                vec![Some(-1), Some(0), Some(-2), Some(1)],
+                vec![Some(1), Some(5), Some(0), Some(3)],
+                vec![None, None, None, Some(0)],
+            ],
+            matrix
+        );
+
+        let mut matrix: Vec<Vec<Option<i64>>> = Vec::new();
+        assert_eq!(Ok(()), floyd_warshall(&mut matrix));
+    }
+
+    #[test]
+    fn test_floyd_warshall_negative_cycle() {
+        // 0 -> 1 -> 2 -> 0 has total weight -1.
+        let mut matrix = vec![
+            vec![Some(0), Some(1), None],
+            vec![None, Some(0), Some(-3)],
+            vec![Some(1), None, Some(0)],
+        ];
+        assert_eq!(Err(NegativeCycle), floyd_warshall(&mut matrix));
+
+        // A negative self loop is a negative cycle too.
+        let mut matrix = vec![vec![Some(-1)]];
+        assert_eq!(Err(NegativeCycle), floyd_warshall(&mut matrix));
+
+        // Every edge between distinct nodes is negative, so distances would
+        // overflow if the cycles weren't caught early.
+        let mut matrix: Vec<Vec<Option<i64>>> =
+            (0..100).map(|u| (0..100).map(|v| (u != v).then_some(-1)).collect()).collect();
+        assert_eq!(Err(NegativeCycle), floyd_warshall(&mut matrix));
+    }
+
+    #[test]
+    fn test_bellman_ford() {
+        // The negative edge 2 -> 1 makes going through 2 shorter, and node 4 is
+        // unreachable.
+        let edges = [(0, 1, 4), (0, 2, 5), (2, 1, -3), (1, 3, 2), (4, 0, 1)];
+        assert_eq!(Ok(vec![Some(0), Some(2), Some(5), Some(4), None]), bellman_ford(&edges, 5, 0));
+        assert_eq!(Ok(vec![None, Some(-3), Some(0), Some(-1), None]), bellman_ford(&edges, 5, 2));
+
+        // The same paths as found by Dijkstra's, on non-negative weights.
+        let edges = [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)];
+        let mut graph = WeightedGraph::directed(5);
+        for (u, v, w) in edges {
+            graph.add_edge(u, v, w as u64);
+        }
+        let expected: Vec<Option<i64>> =
+            dijkstra(&graph, 0).into_iter().map(|d| d.map(|d| d as i64)).collect();
+        assert_eq!(Ok(expected), bellman_ford(&edges, 5, 0));
+
+        assert_eq!(Ok(vec![Some(0)]), bellman_ford(&[], 1, 0));
+    }
+
+    #[test]
+    fn test_bellman_ford_negative_cycle() {
+        // 1 -> 2 -> 3 -> 1 has total weight -1.
+        let edges = [(0, 1, 1), (1, 2, 2), (2, 3, -4), (3, 1, 1)];
+        assert_eq!(Err(NegativeCycle), bellman_ford(&edges, 4, 0));
+
+        // Unreachable from the source, so no distance can be made shorter.
+        let edges = [(1, 2, 2), (2, 1, -4), (0, 3, 1)];
+        assert_eq!(Ok(vec![Some(0), None, None, Some(1)]), bellman_ford(&edges, 4, 0));
+    }
+}
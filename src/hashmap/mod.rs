@@ -0,0 +1,237 @@
+//! # hash map data structures.
+//!
+//! `hashmap` defines a hash map over hashable keys, using open addressing.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// The number of slots allocated by the first insert.
+const MIN_CAPACITY: usize = 8;
+
+/// The fraction of slots, full or deleted, above which the table grows, as a
+/// numerator over a denominator.
+const MAX_LOAD: (usize, usize) = (3, 4);
+
+/// A map from keys to values, stored in a single table of slots using open
+/// addressing with linear probing.
+///
+/// A key's hash picks its home slot. If that's taken by another key, probe the
+/// slots after it in turn, wrapping around, until reaching the key or an empty
+/// slot. Keys that collide therefore sit in a contiguous run after their home
+/// slot.
+///
+/// Removing a key can't simply empty its slot, since that would cut short the
+/// probe of any key placed later in the same run. Instead it leaves a
+/// tombstone, which lookups probe past and inserts reuse.
+///
+/// Once more than 3/4 of the slots are full or tombstones, probe runs grow
+/// long, so the table is rebuilt with twice as many slots as entries, rounded
+/// up to a power of two, which also clears the tombstones.
+///
+/// Insert: O(1) amortised expected.
+/// Get: O(1) expected.
+/// Remove: O(1) expected.
+#[derive(Debug, Clone)]
+pub struct HashMap<K: Hash + Eq, V> {
+    slots: Vec<Slot<K, V>>,
+    // The number of `Slot::Full` slots.
+    len: usize,
+    // The number of `Slot::Deleted` slots.
+    deleted: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Slot<K, V> {
+    Empty,
+    // A tombstone, left by a removed entry.
+    Deleted,
+    Full(K, V),
+}
+
+impl<K: Hash + Eq, V> HashMap<K, V> {
+    /// Creates an empty map, which doesn't allocate until the first insert.
+    pub fn new() -> Self {
+        Self { slots: Vec::new(), len: 0, deleted: 0 }
+    }
+
+    /// Inserts `value` under `key`, returning the value it replaced, if `key`
+    /// was already present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if (self.len + self.deleted + 1) * MAX_LOAD.1 > self.slots.len() * MAX_LOAD.0 {
+            self.grow();
+        }
+
+        match self.probe(&key) {
+            Ok(i) => match &mut self.slots[i] {
+                Slot::Full(_, old) => Some(std::mem::replace(old, value)),
+                _ => unreachable!("probe found a key in a non-full slot"),
+            },
+            Err(i) => {
+                if let Slot::Deleted = self.slots[i] {
+                    self.deleted -= 1;
+                }
+                self.slots[i] = Slot::Full(key, value);
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns the value under `key`, or `None` if it isn't present.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        match &self.slots[self.probe(key).ok()?] {
+            Slot::Full(_, value) => Some(value),
+            _ => unreachable!("probe found a key in a non-full slot"),
+        }
+    }
+
+    /// Removes `key`, returning its value, or `None` if it isn't present.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.probe(key).ok()?;
+        self.len -= 1;
+        self.deleted += 1;
+        match std::mem::replace(&mut self.slots[i], Slot::Deleted) {
+            Slot::Full(_, value) => Some(value),
+            _ => unreachable!("probe found a key in a non-full slot"),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `Ok` with the index of the slot holding `key`, or `Err` with the
+    /// index of the slot to insert it into, i.e. the first tombstone or empty
+    /// slot probed.
+    fn probe(&self, key: &K) -> Result<usize, usize> {
+        // An empty table has no slot to insert into, but then nothing is
+        // present either, and inserts grow the table before probing it.
+        if self.slots.is_empty() {
+            return Err(0);
+        }
+
+        // The capacity is a power of two, so masking wraps the index around.
+        let mask = self.slots.len() - 1;
+        let mut i = hash(key) as usize & mask;
+        let mut tombstone = None;
+        // The load factor keeps at least one slot empty, ending the probe.
+        loop {
+            match &self.slots[i] {
+                Slot::Empty => return Err(tombstone.unwrap_or(i)),
+                Slot::Deleted => {
+                    tombstone.get_or_insert(i);
+                }
+                Slot::Full(k, _) if k == key => return Ok(i),
+                Slot::Full(..) => {}
+            }
+            i = (i + 1) & mask;
+        }
+    }
+
+    /// Rebuilds the table with enough slots for twice the current entries,
+    /// dropping the tombstones.
+    fn grow(&mut self) {
+        let capacity = ((self.len + 1) * 2).next_power_of_two().max(MIN_CAPACITY);
+        let slots = std::iter::repeat_with(|| Slot::Empty).take(capacity).collect();
+        let old = std::mem::replace(&mut self.slots, slots);
+        self.deleted = 0;
+
+        for slot in old {
+            if let Slot::Full(key, value) = slot {
+                // Safe to unwrap the `Err`, every key in the old table is unique.
+                let i = self.probe(&key).unwrap_err();
+                self.slots[i] = Slot::Full(key, value);
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for HashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hashes `key` with the standard library's default hash function.
+fn hash<K: Hash + ?Sized>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash_map() {
+        let mut map = HashMap::new();
+        assert!(map.is_empty());
+        assert_eq!(None, map.get(&"a"));
+        assert_eq!(None, map.remove(&"a"));
+
+        assert_eq!(None, map.insert("a", 1));
+        assert_eq!(None, map.insert("b", 2));
+        assert_eq!(Some(1), map.insert("a", 10));
+        assert_eq!(2, map.len());
+        assert_eq!(Some(&10), map.get(&"a"));
+        assert_eq!(Some(&2), map.get(&"b"));
+
+        assert_eq!(Some(10), map.remove(&"a"));
+        assert_eq!(None, map.remove(&"a"));
+        assert_eq!(None, map.get(&"a"));
+        assert_eq!(1, map.len());
+    }
+
+    #[test]
+    fn test_hash_map_resizes() {
+        let mut map = HashMap::new();
+        for i in 0..1000 {
+            assert_eq!(None, map.insert(i, i * 10));
+        }
+        assert_eq!(1000, map.len());
+
+        for i in (0..1000).step_by(2) {
+            assert_eq!(Some(i * 10), map.remove(&i));
+        }
+        assert_eq!(500, map.len());
+        for i in 0..1000 {
+            let expected = if i % 2 == 0 { None } else { Some(i * 10) };
+            assert_eq!(expected.as_ref(), map.get(&i));
+        }
+
+        // Reinserting reuses the tombstones, and 1000 more keys grow the table.
+        for i in (0..1000).step_by(2) {
+            assert_eq!(None, map.insert(i, i * 100));
+        }
+        for i in 1000..2000 {
+            assert_eq!(None, map.insert(i, i * 100));
+        }
+        assert_eq!(2000, map.len());
+        for i in 0..2000 {
+            let expected = if i < 1000 && i % 2 == 1 { i * 10 } else { i * 100 };
+            assert_eq!(Some(&expected), map.get(&i));
+        }
+    }
+
+    #[test]
+    fn test_hash_map_tombstones() {
+        // Churning one key fills the table with tombstones, which must neither
+        // end probes early nor leave inserts with no empty slot.
+        let mut map = HashMap::new();
+        map.insert(-1, -1);
+        for i in 0..10_000 {
+            map.insert(i, i);
+            assert_eq!(Some(i), map.remove(&i));
+        }
+        assert_eq!(1, map.len());
+        assert_eq!(Some(&-1), map.get(&-1));
+        assert!(map.slots.len() <= 4 * MIN_CAPACITY);
+    }
+}
@@ -0,0 +1,237 @@
+//! # indexed priority queue.
+
+/// A min-priority queue of indices, each with a priority that can be lowered
+/// while it's queued.
+///
+/// The heap is a binary min-heap of `(index, priority)` pairs, as in
+/// [`BinaryHeap`](super::BinaryHeap). Alongside it, `positions` maps each
+/// index to where its pair currently sits in the heap, and is updated on
+/// every swap. That finds an index's pair in O(1), so lowering its priority
+/// only needs to sift the pair up from there.
+///
+/// With a plain heap, e.g. Dijkstra's algorithm has to push a duplicate entry
+/// whenever a node's distance drops, and skip the stale ones as they're
+/// popped. Here each node is queued once, and its priority updated in place.
+///
+/// Push: O(log n).
+/// Pop min: O(log n).
+/// Decrease key: O(log n).
+/// Peek min: O(1).
+#[derive(Debug, Clone)]
+pub struct IndexedPriorityQueue<T: Ord> {
+    heap: Vec<(usize, T)>,
+    // The position in `heap` of each index, or `None` if it isn't queued.
+    positions: Vec<Option<usize>>,
+}
+
+impl<T: Ord> IndexedPriorityQueue<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self { heap: Vec::new(), positions: Vec::new() }
+    }
+
+    /// Queues `index` with `priority`.
+    ///
+    /// Panics if `index` is already queued.
+    pub fn push(&mut self, index: usize, priority: T) {
+        assert!(!self.contains(index), "index {index} is already queued");
+        if self.positions.len() <= index {
+            self.positions.resize(index + 1, None);
+        }
+
+        self.heap.push((index, priority));
+        let i = self.heap.len() - 1;
+        self.positions[index] = Some(i);
+        self.sift_up(i);
+    }
+
+    /// Removes and returns the index with the smallest priority, with its
+    /// priority, or `None` if the queue is empty.
+    pub fn pop_min(&mut self) -> Option<(usize, T)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        // Safe to unwrap, the heap is non-empty.
+        let (index, priority) = self.heap.pop().unwrap();
+        self.positions[index] = None;
+        self.sift_down(0);
+        Some((index, priority))
+    }
+
+    /// Returns the index with the smallest priority, with its priority, or
+    /// `None` if the queue is empty.
+    pub fn peek_min(&self) -> Option<(usize, &T)> {
+        self.heap.first().map(|(index, priority)| (*index, priority))
+    }
+
+    /// Lowers the priority of `index` to `priority`.
+    ///
+    /// Panics if `index` isn't queued, or `priority` is greater than its
+    /// current priority.
+    pub fn decrease_key(&mut self, index: usize, priority: T) {
+        let i = self.position(index).unwrap_or_else(|| panic!("index {index} is not queued"));
+        assert!(priority <= self.heap[i].1, "priority can't be increased");
+        self.heap[i].1 = priority;
+        self.sift_up(i);
+    }
+
+    /// Returns the priority of `index`, or `None` if it isn't queued.
+    pub fn priority(&self, index: usize) -> Option<&T> {
+        self.position(index).map(|i| &self.heap[i].1)
+    }
+
+    /// Returns `true` if `index` is queued.
+    pub fn contains(&self, index: usize) -> bool {
+        self.position(index).is_some()
+    }
+
+    /// Returns the number of queued indices.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if no indices are queued.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the position of `index` in the heap, if it's queued.
+    fn position(&self, index: usize) -> Option<usize> {
+        self.positions.get(index).copied().flatten()
+    }
+
+    /// Swaps the pairs at `a` and `b`, keeping their positions up to date.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions[self.heap[a].0] = Some(a);
+        self.positions[self.heap[b].0] = Some(b);
+    }
+
+    /// Moves the pair at `i` up until its parent's priority is no greater.
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[parent].1 <= self.heap[i].1 {
+                break;
+            }
+            self.swap(parent, i);
+            i = parent;
+        }
+    }
+
+    /// Moves the pair at `i` down until its children's priorities are no
+    /// smaller.
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.heap.len();
+        loop {
+            let left = 2 * i + 1;
+            if left >= len {
+                break;
+            }
+
+            // Pick the smaller of the two children.
+            let right = left + 1;
+            let child =
+                if right < len && self.heap[right].1 < self.heap[left].1 { right } else { left };
+
+            if self.heap[i].1 <= self.heap[child].1 {
+                break;
+            }
+
+            self.swap(i, child);
+            i = child;
+        }
+    }
+}
+
+impl<T: Ord> Default for IndexedPriorityQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_indexed_priority_queue() {
+        let mut queue = IndexedPriorityQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(None, queue.pop_min());
+
+        for (index, priority) in [(0, 50), (1, 10), (2, 40), (3, 30), (4, 20)] {
+            queue.push(index, priority);
+        }
+        assert_eq!(5, queue.len());
+        assert_eq!(Some((1, &10)), queue.peek_min());
+        assert!(queue.contains(3));
+        assert!(!queue.contains(5));
+
+        // Lowering a leaf's priority bubbles it up to the root.
+        queue.decrease_key(0, 5);
+        assert_eq!(Some(&5), queue.priority(0));
+        assert_eq!(Some((0, &5)), queue.peek_min());
+
+        // Lowering to an equal priority is allowed.
+        queue.decrease_key(2, 15);
+        queue.decrease_key(2, 15);
+
+        let mut popped = Vec::new();
+        while let Some(pair) = queue.pop_min() {
+            popped.push(pair);
+        }
+        assert_eq!(vec![(0, 5), (1, 10), (2, 15), (4, 20), (3, 30)], popped);
+        assert!(!queue.contains(0));
+        assert_eq!(None, queue.priority(0));
+
+        // A popped index can be queued again.
+        queue.push(0, 1);
+        assert_eq!(Some((0, 1)), queue.pop_min());
+    }
+
+    #[test]
+    fn test_indexed_priority_queue_many() {
+        let mut queue = IndexedPriorityQueue::new();
+        for index in 0..1000 {
+            queue.push(index, 1000 + (index * 7919) % 1000);
+        }
+        // Lower every other index to below all the rest.
+        for index in (0..1000).step_by(2) {
+            queue.decrease_key(index, index);
+        }
+
+        let popped: Vec<(usize, usize)> = std::iter::from_fn(|| queue.pop_min()).collect();
+        let indices: Vec<usize> = popped.iter().map(|&(i, _)| i).collect();
+        assert_eq!((0..1000).step_by(2).collect::<Vec<_>>(), indices[..500]);
+        assert!(popped.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert!(popped[500..].iter().all(|&(i, p)| i % 2 == 1 && p == 1000 + (i * 7919) % 1000));
+    }
+
+    #[test]
+    #[should_panic(expected = "index 1 is already queued")]
+    fn test_indexed_priority_queue_push_twice() {
+        let mut queue = IndexedPriorityQueue::new();
+        queue.push(1, 10);
+        queue.push(1, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "priority can't be increased")]
+    fn test_indexed_priority_queue_increase_key() {
+        let mut queue = IndexedPriorityQueue::new();
+        queue.push(1, 10);
+        queue.decrease_key(1, 20);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 is not queued")]
+    fn test_indexed_priority_queue_decrease_missing() {
+        let mut queue = IndexedPriorityQueue::new();
+        queue.push(1, 10);
+        queue.decrease_key(2, 5);
+    }
+}
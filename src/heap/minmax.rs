@@ -0,0 +1,243 @@
+//! # min-max heap.
+
+use std::cmp::Ordering;
+
+/// A double-ended priority queue, giving access to both its smallest and its
+/// largest element.
+///
+/// Like [`BinaryHeap`](super::BinaryHeap), the heap is a complete binary tree
+/// stored level by level in a `Vec`, but the levels alternate between min and
+/// max levels, starting with a min level at the root. Every node on a min level
+/// is no larger than any of its descendants, and every node on a max level is
+/// no smaller, so the smallest element is the root and the largest is one of
+/// its children.
+///
+/// To push, append the element as the last leaf. If it's out of order with its
+/// parent, swap them, which fixes the parent's level. Then sift it up through
+/// its grandparents, which share its level, swapping while it's more extreme.
+/// To pop, swap the extreme with the last leaf, remove it, and sift the moved
+/// element down, swapping it with its most extreme child or grandchild, and
+/// any displaced grandchild with its parent if they're then out of order.
+///
+/// Push: O(log n).
+/// Pop min: O(log n).
+/// Pop max: O(log n).
+/// Peek min: O(1).
+/// Peek max: O(1).
+#[derive(Debug, Clone)]
+pub struct MinMaxHeap<T: Ord> {
+    data: Vec<T>,
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    /// Creates an empty heap.
+    pub fn new() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    /// Pushes `item` onto the heap.
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        let i = self.data.len() - 1;
+        if i == 0 {
+            return;
+        }
+
+        // `Less` on a min level, `Greater` on a max level.
+        let order = level_order(i);
+        let parent = (i - 1) / 2;
+        if self.data[i].cmp(&self.data[parent]) == order.reverse() {
+            // Out of order with the parent, so it belongs on the parent's levels.
+            self.data.swap(i, parent);
+            self.sift_up(parent, order.reverse());
+        } else {
+            self.sift_up(i, order);
+        }
+    }
+
+    /// Removes and returns the smallest element, or `None` if the heap is
+    /// empty.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_at(if self.data.is_empty() { None } else { Some(0) })
+    }
+
+    /// Removes and returns the largest element, or `None` if the heap is empty.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_at(self.max_index())
+    }
+
+    /// Returns the smallest element, or `None` if the heap is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the largest element, or `None` if the heap is empty.
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|i| &self.data[i])
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the index of the largest element, i.e. the larger of the root's
+    /// children, or the root if it has none.
+    fn max_index(&self) -> Option<usize> {
+        match self.data.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.data[1] < self.data[2] { 2 } else { 1 }),
+        }
+    }
+
+    /// Removes and returns the element at `i`, if any, restoring the heap
+    /// property.
+    fn pop_at(&mut self, i: Option<usize>) -> Option<T> {
+        let i = i?;
+        let last = self.data.len() - 1;
+        self.data.swap(i, last);
+        let item = self.data.pop();
+        if i < self.data.len() {
+            self.sift_down(i, level_order(i));
+        }
+        item
+    }
+
+    /// Moves the element at `i` up through its grandparents, which order by
+    /// `order`, while it orders before them.
+    fn sift_up(&mut self, mut i: usize, order: Ordering) {
+        while i > 2 {
+            let grandparent = ((i - 1) / 2 - 1) / 2;
+            if self.data[i].cmp(&self.data[grandparent]) != order {
+                break;
+            }
+            self.data.swap(i, grandparent);
+            i = grandparent;
+        }
+    }
+
+    /// Moves the element at `i`, on a level ordering by `order`, down until
+    /// none of its descendants order before it.
+    fn sift_down(&mut self, mut i: usize, order: Ordering) {
+        loop {
+            // Pick the most extreme of the children and grandchildren.
+            let first_child = 2 * i + 1;
+            let descendants = [first_child, first_child + 1]
+                .into_iter()
+                .chain(4 * i + 3..4 * i + 7)
+                .filter(|&d| d < self.data.len());
+            let Some(m) = descendants.reduce(|best, d| {
+                if self.data[d].cmp(&self.data[best]) == order {
+                    d
+                } else {
+                    best
+                }
+            }) else {
+                break;
+            };
+
+            if self.data[m].cmp(&self.data[i]) != order {
+                break;
+            }
+            self.data.swap(i, m);
+
+            // A child is on the opposite levels and has no descendants on `i`'s
+            // levels below it, so it's done. A grandchild shares `i`'s levels,
+            // so carry on from there, after restoring order with its parent.
+            if m <= first_child + 1 {
+                break;
+            }
+            let parent = (m - 1) / 2;
+            if self.data[m].cmp(&self.data[parent]) == order.reverse() {
+                self.data.swap(m, parent);
+            }
+            i = m;
+        }
+    }
+}
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `Less` if the node at `i` is on a min level, or `Greater` if it's on
+/// a max level, i.e. how it orders against its descendants.
+fn level_order(i: usize) -> Ordering {
+    if (i + 1).ilog2().is_multiple_of(2) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts every node orders correctly against all of its descendants.
+    fn assert_min_max_heap<T: Ord>(heap: &MinMaxHeap<T>) {
+        let data = &heap.data;
+        for i in 1..data.len() {
+            // Walk up the ancestors of `i`.
+            let mut ancestor = i;
+            while ancestor > 0 {
+                ancestor = (ancestor - 1) / 2;
+                assert_ne!(level_order(ancestor).reverse(), data[ancestor].cmp(&data[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_max_heap() {
+        let mut heap = MinMaxHeap::new();
+        assert!(heap.is_empty());
+        assert_eq!(None, heap.peek_min());
+        assert_eq!(None, heap.peek_max());
+        assert_eq!(None, heap.pop_min());
+        assert_eq!(None, heap.pop_max());
+
+        heap.push(5);
+        assert_eq!(Some(&5), heap.peek_min());
+        assert_eq!(Some(&5), heap.peek_max());
+        heap.push(1);
+        heap.push(9);
+        heap.push(9);
+        assert_eq!(4, heap.len());
+        assert_eq!(Some(&1), heap.peek_min());
+        assert_eq!(Some(&9), heap.peek_max());
+
+        assert_eq!(Some(9), heap.pop_max());
+        assert_eq!(Some(9), heap.pop_max());
+        assert_eq!(Some(5), heap.pop_max());
+        assert_eq!(Some(1), heap.pop_max());
+        assert_eq!(None, heap.pop_max());
+    }
+
+    #[test]
+    fn test_min_max_heap_alternating() {
+        let mut heap = MinMaxHeap::new();
+        // 37 is coprime with 100, so this is a permutation of 0..100.
+        for i in 0..100 {
+            heap.push((i * 37) % 100);
+            assert_min_max_heap(&heap);
+        }
+
+        // Popping from both ends converges on the middle.
+        for i in 0..50 {
+            assert_eq!(Some(i), heap.pop_min());
+            assert_min_max_heap(&heap);
+            assert_eq!(Some(99 - i), heap.pop_max());
+            assert_min_max_heap(&heap);
+        }
+        assert!(heap.is_empty());
+    }
+}
@@ -0,0 +1,397 @@
+//! # heap data structures.
+//!
+//! `heap` defines various heap data structures on generic types.
+
+use std::cmp::Ordering;
+
+pub mod indexed;
+pub mod minmax;
+
+/// Orders the elements of a [`BinaryHeap`], with the `Greater` element nearer
+/// the root.
+pub trait Comparator<T> {
+    /// Compares `a` with `b`.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Orders larger elements higher, for a max-heap.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MaxComparator;
+
+impl<T: Ord> Comparator<T> for MaxComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Orders smaller elements higher, for a min-heap.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MinComparator;
+
+impl<T: Ord> Comparator<T> for MinComparator {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+/// Orders elements by the key that `F` extracts from them, with larger keys
+/// higher, e.g. `ByKey(|task: &Task| task.priority)`.
+///
+/// For smaller keys higher, wrap the key in [`std::cmp::Reverse`].
+#[derive(Clone, Copy)]
+pub struct ByKey<F>(pub F);
+
+impl<T, K, F> Comparator<T> for ByKey<F>
+where
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        (self.0)(a).cmp(&(self.0)(b))
+    }
+}
+
+/// A binary heap priority queue, ordered by a [`Comparator`], which defaults
+/// to a max-heap.
+///
+/// The heap is a complete binary tree stored level by level in a `Vec`, so the
+/// children of the node at index `i` are at `2i + 1` and `2i + 2`, and its
+/// parent is at `(i - 1) / 2`. Every node orders no lower than its children,
+/// which puts the highest ordered element at the root. A max-heap orders
+/// larger elements higher, so the root is the largest element, a min-heap
+/// orders smaller elements higher, so the root is the smallest element.
+///
+/// To push, append the element as the last leaf and sift it up, swapping it
+/// with its parent while it orders higher. To pop, swap the root with the last
+/// leaf, remove it, and sift the new root down, swapping it with its higher
+/// child while it orders lower.
+///
+/// Push: O(log n).
+/// Pop: O(log n).
+/// Peek: O(1).
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T, C = MaxComparator> {
+    data: Vec<T>,
+    cmp: C,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    /// Creates an empty max-heap.
+    pub fn new() -> Self {
+        Self::with_comparator(MaxComparator)
+    }
+
+    /// Creates a max-heap from the elements of `items`.
+    ///
+    /// See [`BinaryHeap::from_slice_with_comparator`].
+    pub fn from_slice(items: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_slice_with_comparator(items, MaxComparator)
+    }
+}
+
+impl<T: Ord> BinaryHeap<T, MinComparator> {
+    /// Creates an empty min-heap.
+    pub fn new_min() -> Self {
+        Self::with_comparator(MinComparator)
+    }
+
+    /// Creates a min-heap from the elements of `items`.
+    ///
+    /// See [`BinaryHeap::from_slice_with_comparator`].
+    pub fn from_slice_min(items: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_slice_with_comparator(items, MinComparator)
+    }
+}
+
+impl<T, C: Comparator<T>> BinaryHeap<T, C> {
+    /// Creates an empty heap ordered by `cmp`.
+    pub fn with_comparator(cmp: C) -> Self {
+        Self { data: Vec::new(), cmp }
+    }
+
+    /// Creates a heap ordered by `cmp` from the elements of `items`.
+    ///
+    /// The heap is built bottom-up (Floyd's method) by sifting down every
+    /// parent, starting from the last one, which takes O(n) rather than the
+    /// O(n log n) of pushing the elements one at a time.
+    pub fn from_slice_with_comparator(items: &[T], cmp: C) -> Self
+    where
+        T: Clone,
+    {
+        let mut heap = Self { data: items.to_vec(), cmp };
+        for i in (0..heap.data.len() / 2).rev() {
+            heap.sift_down(i);
+        }
+        heap
+    }
+
+    /// Pushes `item` onto the heap.
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Removes and returns the root element, i.e. the highest ordered, such as
+    /// the largest of a max-heap, or `None` if the heap is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let last = self.data.len() - 1;
+        self.data.swap(0, last);
+        let item = self.data.pop();
+        self.sift_down(0);
+        item
+    }
+
+    /// Returns the root element, i.e. the highest ordered, such as the largest
+    /// of a max-heap, or `None` if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if the element at `a` orders lower than the one at `b`.
+    fn lower(&self, a: usize, b: usize) -> bool {
+        self.cmp.compare(&self.data[a], &self.data[b]) == Ordering::Less
+    }
+
+    /// Moves the element at `i` up until its parent orders no lower.
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.lower(parent, i) {
+                break;
+            }
+            self.data.swap(parent, i);
+            i = parent;
+        }
+    }
+
+    /// Moves the element at `i` down until its children order no higher.
+    fn sift_down(&mut self, mut i: usize) {
+        let len = self.data.len();
+        loop {
+            let left = 2 * i + 1;
+            if left >= len {
+                break;
+            }
+
+            // Pick the higher of the two children.
+            let right = left + 1;
+            let child = if right < len && self.lower(left, right) { right } else { left };
+
+            if !self.lower(i, child) {
+                break;
+            }
+
+            self.data.swap(i, child);
+            i = child;
+        }
+    }
+}
+
+impl<T, C: Comparator<T> + Default> Default for BinaryHeap<T, C> {
+    fn default() -> Self {
+        Self::with_comparator(C::default())
+    }
+}
+
+/// Returns the `k` largest elements of `items`, in descending order, or all of
+/// them if `k` is at least `items.len()`.
+///
+/// Keep the largest elements seen so far on a min-heap of at most `k`
+/// elements, so its root is the smallest of them. Each further element that's
+/// larger than the root replaces it. Once every element has been seen, pop the
+/// heap into reverse order.
+///
+/// Only `k` elements are held at once, and each heap operation costs O(log k),
+/// so for k much smaller than n this beats sorting all of `items`.
+///
+/// Worst-case performance: O(n log k).
+/// Worst-case space complexity: O(k) auxiliary.
+pub fn top_k<T: Ord + Clone>(items: &[T], k: usize) -> Vec<T> {
+    let mut heap = BinaryHeap::new_min();
+    for item in items {
+        if heap.len() < k {
+            heap.push(item.clone());
+        } else if heap.peek().is_some_and(|smallest| item > smallest) {
+            heap.pop();
+            heap.push(item.clone());
+        }
+    }
+
+    let mut largest = Vec::with_capacity(heap.len());
+    while let Some(item) = heap.pop() {
+        largest.push(item);
+    }
+    largest.reverse();
+    largest
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Reverse;
+
+    use super::*;
+
+    #[test]
+    fn test_binary_heap() {
+        let mut heap = BinaryHeap::new();
+        assert!(heap.is_empty());
+
+        for i in [5, 1, 9, 3, 7, 2, 8, 6, 4, 0, 9] {
+            heap.push(i);
+        }
+        assert_eq!(11, heap.len());
+        assert_eq!(Some(&9), heap.peek());
+
+        let mut popped = Vec::new();
+        while let Some(i) = heap.pop() {
+            popped.push(i);
+        }
+        assert_eq!(vec![9, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0], popped);
+        assert!(heap.is_empty());
+        assert_eq!(None, heap.pop());
+    }
+
+    #[test]
+    fn test_binary_heap_from_slice() {
+        let mut heap = BinaryHeap::from_slice(&["b", "d", "a", "c"]);
+        assert_eq!(4, heap.len());
+        assert_eq!(Some("d"), heap.pop());
+        assert_eq!(Some("c"), heap.pop());
+        assert_eq!(Some("b"), heap.pop());
+        assert_eq!(Some("a"), heap.pop());
+        assert_eq!(None, heap.pop());
+
+        let list: Vec<i32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+        let mut heap = BinaryHeap::from_slice(&list);
+        for i in (0..1000).rev() {
+            assert_eq!(Some(i), heap.pop());
+        }
+    }
+
+    #[test]
+    fn test_binary_heap_peek_empty() {
+        let heap: BinaryHeap<i32> = BinaryHeap::new();
+        assert_eq!(None, heap.peek());
+    }
+
+    #[test]
+    fn test_binary_heap_min() {
+        let mut heap = BinaryHeap::new_min();
+        for i in [5, 1, 9, 3, 7, 2, 8, 6, 4, 0, 9] {
+            heap.push(i);
+        }
+        assert_eq!(Some(&0), heap.peek());
+
+        let mut popped = Vec::new();
+        while let Some(i) = heap.pop() {
+            popped.push(i);
+            if let Some(&next) = heap.peek() {
+                assert!(i <= next);
+            }
+        }
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 9], popped);
+
+        let mut heap = BinaryHeap::from_slice_min(&["b", "d", "a", "c"]);
+        assert_eq!(Some(&"a"), heap.peek());
+        assert_eq!(Some("a"), heap.pop());
+        assert_eq!(Some(&"b"), heap.peek());
+        heap.push("0");
+        assert_eq!(Some(&"0"), heap.peek());
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Task {
+        name: &'static str,
+        priority: u32,
+    }
+
+    #[test]
+    fn test_binary_heap_by_key() {
+        let tasks = [
+            Task { name: "write", priority: 2 },
+            Task { name: "test", priority: 5 },
+            Task { name: "review", priority: 1 },
+            Task { name: "ship", priority: 3 },
+        ];
+
+        // `Task` isn't `Ord`, only its priority is.
+        let mut heap = BinaryHeap::with_comparator(ByKey(|task: &Task| task.priority));
+        for task in tasks.iter().cloned() {
+            heap.push(task);
+        }
+        assert_eq!(Some("test"), heap.peek().map(|task| task.name));
+
+        let mut popped = Vec::new();
+        while let Some(task) = heap.pop() {
+            popped.push(task.name);
+        }
+        assert_eq!(vec!["test", "ship", "write", "review"], popped);
+
+        // Reversing the key orders the lowest priority first.
+        let mut heap = BinaryHeap::from_slice_with_comparator(
+            &tasks,
+            ByKey(|task: &Task| Reverse(task.priority)),
+        );
+        assert_eq!(4, heap.len());
+        assert_eq!(Some("review"), heap.pop().map(|task| task.name));
+        assert_eq!(Some("write"), heap.pop().map(|task| task.name));
+        heap.push(Task { name: "plan", priority: 0 });
+        assert_eq!(Some("plan"), heap.pop().map(|task| task.name));
+    }
+
+    #[test]
+    fn test_comparators() {
+        assert_eq!(Ordering::Greater, MaxComparator.compare(&2, &1));
+        assert_eq!(Ordering::Less, MinComparator.compare(&2, &1));
+        assert_eq!(Ordering::Equal, MinComparator.compare(&"a", &"a"));
+        assert_eq!(Ordering::Less, ByKey(|s: &&str| s.len()).compare(&"bb", &"aaa"));
+
+        // The default heap is a max-heap.
+        let mut heap: BinaryHeap<i32> = BinaryHeap::default();
+        heap.push(1);
+        heap.push(3);
+        assert_eq!(Some(3), heap.pop());
+
+        let mut heap: BinaryHeap<i32, MinComparator> = BinaryHeap::default();
+        heap.push(1);
+        heap.push(3);
+        assert_eq!(Some(1), heap.pop());
+    }
+
+    #[test]
+    fn test_top_k() {
+        assert_eq!(vec![9, 7], top_k(&[5, 1, 9, 3, 7], 2));
+        assert_eq!(vec![9], top_k(&[5, 1, 9, 3, 7], 1));
+        assert_eq!(Vec::<i32>::new(), top_k(&[5, 1, 9, 3, 7], 0));
+        assert_eq!(vec![9, 9, 7], top_k(&[9, 1, 9, 3, 7], 3));
+        assert_eq!(vec!["d", "c"], top_k(&["b", "d", "a", "c"], 2));
+
+        // Too large a `k` returns everything, sorted.
+        assert_eq!(vec![9, 7, 5, 3, 1], top_k(&[5, 1, 9, 3, 7], 5));
+        assert_eq!(vec![9, 7, 5, 3, 1], top_k(&[5, 1, 9, 3, 7], 100));
+        assert_eq!(Vec::<i32>::new(), top_k(&[], 3));
+
+        let list: Vec<i32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+        assert_eq!((990..1000).rev().collect::<Vec<_>>(), top_k(&list, 10));
+    }
+}
@@ -0,0 +1,24 @@
+//! # dsa
+//!
+//! Data Structures & Algorithms in Rust.
+
+pub mod bloom;
+pub mod cache;
+pub mod deque;
+pub mod dp;
+pub mod dsu;
+pub mod fenwick;
+pub mod graph;
+pub mod hashmap;
+pub mod heap;
+pub mod list;
+pub mod math;
+pub mod queue;
+pub mod search;
+pub mod segtree;
+pub mod skiplist;
+pub mod sort;
+pub mod stack;
+pub mod strings;
+pub mod tree;
+pub mod trie;
@@ -0,0 +1,293 @@
+//! # doubly linked list.
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/// A doubly linked list.
+///
+/// Each node links to both the previous and the next node, and the list links
+/// to the first node, the head, and the last node, the tail. Elements can be
+/// pushed and popped at either end, and iterated in either direction.
+///
+/// Since every node is pointed to from two places, the links are raw pointers
+/// rather than `Box`, with the list upholding these invariants:
+///
+/// - Every node was allocated by `Box` and is owned by exactly one list, which
+///   frees it with `Box::from_raw` when it's popped, at the latest on drop.
+/// - `head` and `tail` are `None` exactly when the list is empty.
+/// - For every node, `next.prev` and `prev.next`, when present, point back to
+///   it, the head has no `prev` and the tail has no `next`.
+///
+/// Push front/back: O(1).
+/// Pop front/back: O(1).
+/// Peek front/back: O(1).
+pub struct DoublyLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    // The list owns its nodes, for the purposes of drop check and variance.
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self { head: None, tail: None, len: 0, _marker: PhantomData }
+    }
+
+    /// Pushes `value` onto the front of the list.
+    pub fn push_front(&mut self, value: T) {
+        let node = NonNull::from(Box::leak(Box::new(Node { value, prev: None, next: self.head })));
+        match self.head {
+            // SAFETY: `head` is a live node owned by this list.
+            Some(mut head) => unsafe { head.as_mut().prev = Some(node) },
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    /// Pushes `value` onto the back of the list.
+    pub fn push_back(&mut self, value: T) {
+        let node = NonNull::from(Box::leak(Box::new(Node { value, prev: self.tail, next: None })));
+        match self.tail {
+            // SAFETY: `tail` is a live node owned by this list.
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(node) },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            // SAFETY: `head` is a live node owned by this list, and once it's
+            // unlinked below nothing else points to it, so the list can take
+            // back ownership of the allocation.
+            let node = unsafe { Box::from_raw(head.as_ptr()) };
+            self.head = node.next;
+            match self.head {
+                // SAFETY: the new head is a live node owned by this list.
+                Some(mut head) => unsafe { head.as_mut().prev = None },
+                None => self.tail = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Removes and returns the back element, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|tail| {
+            // SAFETY: `tail` is a live node owned by this list, and once it's
+            // unlinked below nothing else points to it, so the list can take
+            // back ownership of the allocation.
+            let node = unsafe { Box::from_raw(tail.as_ptr()) };
+            self.tail = node.prev;
+            match self.tail {
+                // SAFETY: the new tail is a live node owned by this list.
+                Some(mut tail) => unsafe { tail.as_mut().next = None },
+                None => self.head = None,
+            }
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Returns the front element, or `None` if the list is empty.
+    pub fn peek_front(&self) -> Option<&T> {
+        // SAFETY: `head` is a live node owned by this list, borrowed for as
+        // long as `self` is.
+        self.head.map(|head| unsafe { &(*head.as_ptr()).value })
+    }
+
+    /// Returns the back element, or `None` if the list is empty.
+    pub fn peek_back(&self) -> Option<&T> {
+        // SAFETY: `tail` is a live node owned by this list, borrowed for as
+        // long as `self` is.
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).value })
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the elements, from front to back.
+    ///
+    /// The iterator is double-ended, so `iter().rev()` iterates from back to
+    /// front.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { front: self.head, back: self.tail, len: self.len, _marker: PhantomData }
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    /// Frees the nodes one at a time, from the front.
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of a [`DoublyLinkedList`].
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    // The number of elements not yet yielded from either end, so the two ends
+    // stop when they meet.
+    len: usize,
+    _marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.front.map(|front| {
+            // SAFETY: `front` is a live node of a list that is borrowed for `'a`.
+            let node = unsafe { &*front.as_ptr() };
+            self.front = node.next;
+            self.len -= 1;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.back.map(|back| {
+            // SAFETY: `back` is a live node of a list that is borrowed for `'a`.
+            let node = unsafe { &*back.as_ptr() };
+            self.back = node.prev;
+            self.len -= 1;
+            &node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_doubly_linked_list() {
+        let mut list = DoublyLinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(None, list.pop_front());
+        assert_eq!(None, list.pop_back());
+
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&1), list.peek_front());
+        assert_eq!(Some(&3), list.peek_back());
+
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(Some(3), list.pop_back());
+        assert_eq!(Some(2), list.pop_back());
+        assert_eq!(None, list.pop_front());
+        assert_eq!(None, list.peek_back());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_doubly_linked_list_iter() {
+        let mut list = DoublyLinkedList::new();
+        for s in ["a", "b", "c", "d"] {
+            list.push_back(s);
+        }
+        assert_eq!(vec![&"a", &"b", &"c", &"d"], list.iter().collect::<Vec<_>>());
+        assert_eq!(vec![&"d", &"c", &"b", &"a"], list.iter().rev().collect::<Vec<_>>());
+
+        // The two ends meet in the middle without yielding anything twice.
+        let mut iter = list.iter();
+        assert_eq!(Some(&"a"), iter.next());
+        assert_eq!(Some(&"d"), iter.next_back());
+        assert_eq!(Some(&"b"), iter.next());
+        assert_eq!(Some(&"c"), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn test_doubly_linked_list_interleaved() {
+        let mut list = DoublyLinkedList::new();
+        let mut expected = std::collections::VecDeque::new();
+        for i in 0..100 {
+            match i % 4 {
+                0 => {
+                    list.push_front(i);
+                    expected.push_front(i);
+                }
+                1 | 2 => {
+                    list.push_back(i);
+                    expected.push_back(i);
+                }
+                _ => assert_eq!(expected.pop_front(), list.pop_front()),
+            }
+            if i % 7 == 0 {
+                assert_eq!(expected.pop_back(), list.pop_back());
+            }
+            assert_eq!(expected.len(), list.len());
+        }
+        assert!(expected.iter().eq(list.iter()));
+        assert!(expected.iter().rev().eq(list.iter().rev()));
+    }
+
+    #[test]
+    fn test_doubly_linked_list_drop_long() {
+        let mut list = DoublyLinkedList::new();
+        for i in 0..100_000 {
+            list.push_back(i.to_string());
+        }
+        drop(list);
+    }
+}
@@ -0,0 +1,6 @@
+//! # list data structures.
+//!
+//! `list` defines various linked list data structures on generic types.
+
+pub mod doubly;
+pub mod singly;
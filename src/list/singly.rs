@@ -0,0 +1,160 @@
+//! # singly linked list.
+
+/// A singly linked list.
+///
+/// Each node owns the next node through an `Option<Box<_>>`, ending with
+/// `None`, and the list owns the first node, the head. Elements can only be
+/// accessed from the head, so pushing and popping there is cheap but reaching
+/// the nth element takes n steps.
+///
+/// Push front: O(1).
+/// Pop front: O(1).
+/// Peek front: O(1).
+#[derive(Debug)]
+pub struct SinglyLinkedList<T> {
+    head: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    next: Option<Box<Node<T>>>,
+}
+
+impl<T> SinglyLinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Pushes `value` onto the front of the list.
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Returns the front element, or `None` if the list is empty.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Returns an iterator over the elements, from front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+}
+
+impl<T> Default for SinglyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for SinglyLinkedList<T> {
+    /// Drops the nodes one at a time.
+    ///
+    /// The default drop of the head would recursively drop the next node
+    /// before returning, which overflows the stack for long lists.
+    fn drop(&mut self) {
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+/// An iterator over the elements of a [`SinglyLinkedList`].
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SinglyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_singly_linked_list() {
+        let mut list = SinglyLinkedList::new();
+        assert!(list.is_empty());
+        assert_eq!(None, list.peek_front());
+        assert_eq!(None, list.pop_front());
+
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        assert_eq!(3, list.len());
+        assert_eq!(Some(&3), list.peek_front());
+
+        assert_eq!(Some(3), list.pop_front());
+        assert_eq!(Some(2), list.pop_front());
+        list.push_front(4);
+        assert_eq!(Some(4), list.pop_front());
+        assert_eq!(Some(1), list.pop_front());
+        assert_eq!(None, list.pop_front());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_singly_linked_list_iter() {
+        let mut list = SinglyLinkedList::new();
+        for s in ["a", "b", "c"] {
+            list.push_front(s);
+        }
+        assert_eq!(vec![&"c", &"b", &"a"], list.iter().collect::<Vec<_>>());
+        assert_eq!(3, list.len());
+
+        let empty: SinglyLinkedList<i32> = SinglyLinkedList::new();
+        assert_eq!(None, empty.iter().next());
+    }
+
+    #[test]
+    fn test_singly_linked_list_drop_long() {
+        let mut list = SinglyLinkedList::new();
+        for i in 0..100_000 {
+            list.push_front(i);
+        }
+        assert_eq!(100_000, list.len());
+        drop(list);
+    }
+}
@@ -1,6 +1,3 @@
-mod search;
-mod sort;
-
 fn main() {
     println!("Hello, world!");
 }
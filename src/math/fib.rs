@@ -0,0 +1,113 @@
+//! # Fibonacci numbers.
+
+/// The largest `n` whose Fibonacci number fits in a `u128`.
+pub const MAX_FIB_N: u64 = 186;
+
+/// Returns the `n`th Fibonacci number, where `fib(0) == 0` and `fib(1) == 1`,
+/// using fast doubling.
+///
+/// Fast doubling is matrix exponentiation of `[[1, 1], [1, 0]]` with the
+/// redundant entries dropped, giving the identities:
+///
+/// F(2k) = F(k) * (2F(k + 1) - F(k))
+/// F(2k + 1) = F(k)^2 + F(k + 1)^2
+///
+/// So F(n) is found from F(n / 2) and F(n / 2 + 1), halving n at each step.
+///
+/// Panics if `n` is greater than [`MAX_FIB_N`], since the result would
+/// overflow.
+///
+/// Worst-case performance: O(log n).
+/// Worst-case space complexity: O(log n) auxiliary.
+pub fn fib(n: u64) -> u128 {
+    assert!(n <= MAX_FIB_N, "fib({n}) overflows u128");
+
+    // Only the lower number of the top level pair is needed, its partner
+    // F(n + 1) would overflow for n == MAX_FIB_N.
+    let (a, b) = fib_pair(n / 2);
+    if n.is_multiple_of(2) {
+        a * (2 * b - a)
+    } else {
+        a * a + b * b
+    }
+}
+
+/// Returns `(F(k), F(k + 1))`.
+fn fib_pair(k: u64) -> (u128, u128) {
+    if k == 0 {
+        return (0, 1);
+    }
+
+    let (a, b) = fib_pair(k / 2);
+    let (even, odd) = (a * (2 * b - a), a * a + b * b);
+    if k.is_multiple_of(2) {
+        (even, odd)
+    } else {
+        (odd, even + odd)
+    }
+}
+
+/// Returns the `n`th Fibonacci number, using and extending `memo`.
+///
+/// `memo` holds the Fibonacci numbers F(0), F(1), ... computed so far, and is
+/// extended up to F(n) by summing the last two entries. Repeated queries
+/// against the same `memo` are then O(1) for any `n` already covered. An
+/// empty `Vec` is a valid starting point.
+///
+/// Panics if `n` is greater than [`MAX_FIB_N`], since the result would
+/// overflow.
+///
+/// Worst-case performance: O(n) for the first query, O(1) once memoised.
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn fib_memo(n: u64, memo: &mut Vec<u128>) -> u128 {
+    assert!(n <= MAX_FIB_N, "fib({n}) overflows u128");
+
+    if memo.len() < 2 {
+        *memo = vec![0, 1];
+    }
+    let n = n as usize;
+    while memo.len() <= n {
+        let len = memo.len();
+        memo.push(memo[len - 1] + memo[len - 2]);
+    }
+    memo[n]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TABLE: [u128; 21] =
+        [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610, 987, 1597, 2584, 4181, 6765];
+
+    #[test]
+    fn test_fib() {
+        for (n, &expected) in TABLE.iter().enumerate() {
+            assert_eq!(expected, fib(n as u64));
+        }
+        assert_eq!(2_880_067_194_370_816_120, fib(90));
+        assert_eq!(332_825_110_087_067_562_321_196_029_789_634_457_848, fib(MAX_FIB_N));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_fib_overflow() {
+        fib(MAX_FIB_N + 1);
+    }
+
+    #[test]
+    fn test_fib_memo() {
+        let mut memo = Vec::new();
+        assert_eq!(2_880_067_194_370_816_120, fib_memo(90, &mut memo));
+        assert_eq!(91, memo.len());
+
+        for (n, &expected) in TABLE.iter().enumerate() {
+            assert_eq!(expected, fib_memo(n as u64, &mut memo));
+        }
+        assert_eq!(91, memo.len());
+
+        for n in 0..=MAX_FIB_N {
+            assert_eq!(fib(n), fib_memo(n, &mut memo));
+        }
+    }
+}
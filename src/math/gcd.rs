@@ -0,0 +1,71 @@
+//! # greatest common divisor and least common multiple.
+
+/// Returns the greatest common divisor of `a` and `b`, using Euclid's
+/// algorithm.
+///
+/// Any common divisor of a and b also divides a mod b, so gcd(a, b) equals
+/// gcd(b, a mod b). Repeat until the remainder is 0, at which point the other
+/// number is the gcd.
+///
+/// Every number divides 0, so gcd(a, 0) is `a`, and `gcd(0, 0)` is defined as
+/// 0 rather than left undefined.
+///
+/// Worst-case performance: O(log min(a, b)).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`.
+///
+/// Computed as `a / gcd(a, b) * b`, dividing first so the intermediate result
+/// can't overflow when the lcm itself fits. `lcm(a, 0)` is 0.
+///
+/// Panics if the result overflows `u64`.
+///
+/// Worst-case performance: O(log min(a, b)).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b)).checked_mul(b).expect("lcm overflows u64")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(1, gcd(17, 31));
+        assert_eq!(1, gcd(8, 9));
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(6, gcd(18, 48));
+        assert_eq!(7, gcd(7, 7));
+        assert_eq!(5, gcd(5, 0));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(0, gcd(0, 0));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(17 * 31, lcm(17, 31));
+        assert_eq!(144, lcm(48, 18));
+        assert_eq!(7, lcm(7, 7));
+        assert_eq!(0, lcm(5, 0));
+        assert_eq!(0, lcm(0, 5));
+        assert_eq!(0, lcm(0, 0));
+        // Multiplying first would overflow.
+        assert_eq!(u64::MAX, lcm(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows")]
+    fn test_lcm_overflow() {
+        lcm(u64::MAX, u64::MAX - 1);
+    }
+}
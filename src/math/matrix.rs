@@ -0,0 +1,200 @@
+//! # matrix.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A dense matrix of `i64`s, stored row by row in a single `Vec`.
+///
+/// The element at row `r` and column `c`, counting from 0, is indexed by
+/// `(r, c)`, and stored at `r * cols + c`.
+///
+/// Index: O(1).
+/// Multiply: O(n m p), for an n by m matrix times an m by p matrix.
+/// Transpose: O(n m).
+/// Space complexity: O(n m).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<i64>,
+}
+
+impl Matrix {
+    /// Creates a `rows` by `cols` matrix of zeros.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![0; rows * cols] }
+    }
+
+    /// Creates the `n` by `n` identity matrix, with ones on the diagonal and
+    /// zeros everywhere else.
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::new(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1;
+        }
+        matrix
+    }
+
+    /// Creates a matrix from its rows.
+    pub fn from_rows<const C: usize>(rows: &[[i64; C]]) -> Self {
+        Self { rows: rows.len(), cols: C, data: rows.concat() }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the element at row `r` and column `c`, or `None` if either is
+    /// out of bounds.
+    pub fn get(&self, r: usize, c: usize) -> Option<&i64> {
+        (r < self.rows && c < self.cols).then(|| &self.data[r * self.cols + c])
+    }
+
+    /// Returns a mutable reference to the element at row `r` and column `c`,
+    /// or `None` if either is out of bounds.
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut i64> {
+        (r < self.rows && c < self.cols).then(|| &mut self.data[r * self.cols + c])
+    }
+
+    /// Returns the product `self * rhs`, or an error if `self` doesn't have as
+    /// many columns as `rhs` has rows.
+    ///
+    /// Each element of the product, at row `r` and column `c`, is the dot
+    /// product of row `r` of `self` with column `c` of `rhs`. The loops run
+    /// over `r`, then the shared dimension, then `c`, so both `rhs` and the
+    /// product are walked along their rows, which are contiguous in memory.
+    ///
+    /// Panics if an element overflows, in debug builds.
+    pub fn multiply(&self, rhs: &Matrix) -> Result<Matrix, DimensionError> {
+        if self.cols != rhs.rows {
+            return Err(DimensionError);
+        }
+
+        let mut product = Matrix::new(self.rows, rhs.cols);
+        for r in 0..self.rows {
+            for k in 0..self.cols {
+                let lhs = self[(r, k)];
+                for c in 0..rhs.cols {
+                    product[(r, c)] += lhs * rhs[(k, c)];
+                }
+            }
+        }
+        Ok(product)
+    }
+
+    /// Returns the transpose, i.e. the matrix with the rows of `self` as its
+    /// columns.
+    pub fn transpose(&self) -> Matrix {
+        let mut transpose = Matrix::new(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                transpose[(c, r)] = self[(r, c)];
+            }
+        }
+        transpose
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = i64;
+
+    /// Panics if `r` or `c` is out of bounds.
+    fn index(&self, (r, c): (usize, usize)) -> &i64 {
+        self.get(r, c).unwrap_or_else(|| panic!("index ({r}, {c}) is out of bounds"))
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    /// Panics if `r` or `c` is out of bounds.
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut i64 {
+        self.get_mut(r, c).unwrap_or_else(|| panic!("index ({r}, {c}) is out of bounds"))
+    }
+}
+
+/// The error returned when multiplying matrices whose dimensions don't match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionError;
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matrix dimensions don't match")
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matrix() {
+        let mut matrix = Matrix::new(2, 3);
+        assert_eq!(2, matrix.rows());
+        assert_eq!(3, matrix.cols());
+        assert_eq!(0, matrix[(1, 2)]);
+
+        matrix[(1, 2)] = 7;
+        matrix[(0, 1)] = -2;
+        assert_eq!(Matrix::from_rows(&[[0, -2, 0], [0, 0, 7]]), matrix);
+        assert_eq!(Some(&7), matrix.get(1, 2));
+        assert_eq!(None, matrix.get(2, 0));
+        assert_eq!(None, matrix.get(0, 3));
+
+        assert_eq!(Matrix::from_rows(&[[1, 0], [0, 1]]), Matrix::identity(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "index (0, 3) is out of bounds")]
+    fn test_matrix_index_out_of_bounds() {
+        // Within the data, but past the end of the row.
+        let _ = Matrix::new(2, 3)[(0, 3)];
+    }
+
+    #[test]
+    fn test_matrix_multiply() {
+        let lhs = Matrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+        let rhs = Matrix::from_rows(&[[7, 8], [9, 10], [11, 12]]);
+        // [1*7 + 2*9 + 3*11, 1*8 + 2*10 + 3*12]
+        // [4*7 + 5*9 + 6*11, 4*8 + 5*10 + 6*12]
+        assert_eq!(Ok(Matrix::from_rows(&[[58, 64], [139, 154]])), lhs.multiply(&rhs));
+        assert_eq!(
+            Ok(Matrix::from_rows(&[[39, 54, 69], [49, 68, 87], [59, 82, 105]])),
+            rhs.multiply(&lhs)
+        );
+
+        assert_eq!(Err(DimensionError), lhs.multiply(&lhs));
+        assert_eq!(Err(DimensionError), Matrix::new(3, 2).multiply(&Matrix::new(3, 2)));
+
+        // Powers of [[1, 1], [1, 0]] hold the Fibonacci numbers.
+        let step = Matrix::from_rows(&[[1, 1], [1, 0]]);
+        let mut power = Matrix::identity(2);
+        for _ in 0..10 {
+            power = power.multiply(&step).unwrap();
+        }
+        assert_eq!(Matrix::from_rows(&[[89, 55], [55, 34]]), power);
+    }
+
+    #[test]
+    fn test_matrix_multiply_identity() {
+        let matrix = Matrix::from_rows(&[[1, -2, 3], [4, 5, -6]]);
+        assert_eq!(Ok(matrix.clone()), Matrix::identity(2).multiply(&matrix));
+        assert_eq!(Ok(matrix.clone()), matrix.multiply(&Matrix::identity(3)));
+        assert_eq!(Ok(Matrix::new(0, 0)), Matrix::identity(0).multiply(&Matrix::new(0, 0)));
+    }
+
+    #[test]
+    fn test_matrix_transpose() {
+        let matrix = Matrix::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+        let transpose = matrix.transpose();
+        assert_eq!(Matrix::from_rows(&[[1, 4], [2, 5], [3, 6]]), transpose);
+        assert_eq!(matrix, transpose.transpose());
+        assert_eq!(Matrix::identity(4), Matrix::identity(4).transpose());
+    }
+}
@@ -0,0 +1,9 @@
+//! # math algorithms.
+//!
+//! `math` defines various number theoretic and numeric algorithms.
+
+pub mod fib;
+pub mod gcd;
+pub mod matrix;
+pub mod modular;
+pub mod primes;
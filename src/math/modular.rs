@@ -0,0 +1,113 @@
+//! # modular arithmetic.
+
+/// Returns `base^exp mod modulus`, using binary exponentiation.
+///
+/// Write exp in binary, so base^exp is the product of base^(2^i) over each set
+/// bit i. Square the base once per bit, from least to most significant, and
+/// multiply it into the result wherever the bit is set, reducing mod `modulus`
+/// after every step. Products are taken in `u128`, so they can't overflow
+/// before being reduced.
+///
+/// Panics if `modulus` is 0.
+///
+/// Worst-case performance: O(log exp).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "modulus must be non-zero");
+
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    // Everything is 0 mod 1, including base^0.
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    // Reduced mod a `u64`, so it fits in one.
+    result as u64
+}
+
+/// Returns the inverse of `a` mod `m`, i.e. the x in `0..m` with
+/// `a * x mod m == 1`, or `None` if there isn't one, which is when `a` and `m`
+/// share a factor.
+///
+/// The extended Euclidean algorithm finds x and y with `a * x + m * y ==
+/// gcd(a, m)`, by tracking how each remainder of Euclid's algorithm is made
+/// from a and m. If the gcd is 1, then `a * x` is 1 mod m, so x is the
+/// inverse.
+///
+/// Panics if `m` is 0.
+///
+/// Worst-case performance: O(log min(a, m)).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    assert!(m != 0, "modulus must be non-zero");
+
+    // Each remainder `r`, with its coefficient `x` of a, i.e. `r == a * x mod m`.
+    let (mut r0, mut r1) = (i128::from(m), i128::from(a % m));
+    let (mut x0, mut x1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+    }
+
+    if r0 != 1 {
+        return None;
+    }
+    // Within `-m..m`, so it fits in a `u64` once made non-negative.
+    Some(x0.rem_euclid(i128::from(m)) as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(24, mod_pow(2, 10, 1000));
+        assert_eq!(1, mod_pow(7, 0, 13));
+        assert_eq!(0, mod_pow(0, 5, 13));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(3, mod_pow(3, 1, 7));
+
+        // Fermat's little theorem, a^(p - 1) is 1 mod a prime p.
+        assert_eq!(1, mod_pow(2, 1_000_000_006, 1_000_000_007));
+        assert_eq!(1, mod_pow(123_456_789, u64::MAX - 59, u64::MAX - 58));
+
+        // Squaring these overflows a `u64`, and m - 1 is -1 mod m.
+        let m = u64::MAX;
+        assert_eq!(1, mod_pow(m - 1, 2, m));
+        assert_eq!(m - 1, mod_pow(m - 1, 3, m));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be non-zero")]
+    fn test_mod_pow_zero_modulus() {
+        mod_pow(2, 10, 0);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(1), mod_inverse(1, 11));
+        assert_eq!(Some(10), mod_inverse(10, 11));
+        assert_eq!(Some(4), mod_inverse(14, 11));
+        assert_eq!(None, mod_inverse(0, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+        assert_eq!(Some(0), mod_inverse(5, 1));
+
+        let p = 1_000_000_007;
+        for a in [2, 3, 12345, p - 1] {
+            let inverse = mod_inverse(a, p).unwrap();
+            assert_eq!(1, u128::from(a) * u128::from(inverse) % u128::from(p));
+        }
+
+        let m = u64::MAX;
+        let inverse = mod_inverse(2, m).unwrap();
+        assert_eq!(1, 2 * u128::from(inverse) % u128::from(m));
+    }
+}
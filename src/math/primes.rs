@@ -0,0 +1,161 @@
+//! # prime numbers.
+
+/// Returns all primes up to and including `limit`, using the sieve of
+/// Eratosthenes.
+///
+/// Start with every number marked as a candidate prime. For each candidate p,
+/// in increasing order, cross off its multiples from p^2 upwards, since the
+/// smaller multiples have a smaller prime factor and were already crossed off.
+/// Once p^2 passes `limit`, every remaining candidate is prime.
+///
+/// 2 is the only even prime, so the sieve only tracks odd numbers, halving
+/// its size, with index `i` standing for `2i + 1`.
+///
+/// Worst-case performance: O(n log log n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn sieve(limit: usize) -> Vec<usize> {
+    if limit < 2 {
+        return Vec::new();
+    }
+
+    // `candidate[i]` is whether `2i + 1` may be prime. 1 is not.
+    let mut candidate = vec![true; limit.div_ceil(2)];
+    candidate[0] = false;
+
+    let mut p = 3;
+    while p * p <= limit {
+        if candidate[p / 2] {
+            // Step by 2p to skip the even multiples.
+            for multiple in (p * p..=limit).step_by(2 * p) {
+                candidate[multiple / 2] = false;
+            }
+        }
+        p += 2;
+    }
+
+    let odd_primes = candidate.iter().enumerate().filter(|(_, &c)| c).map(|(i, _)| 2 * i + 1);
+    std::iter::once(2).chain(odd_primes).collect()
+}
+
+/// Returns `true` if `n` is prime, using trial division.
+///
+/// A composite n has a factor no greater than sqrt(n), so it's enough to try
+/// dividing by 2, then by every odd number up to sqrt(n).
+///
+/// Worst-case performance: O(sqrt(n)).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n.is_multiple_of(2) {
+        return n == 2;
+    }
+
+    // `d <= n / d` rather than `d * d <= n`, which can overflow.
+    let mut d = 3;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            return false;
+        }
+        d += 2;
+    }
+    true
+}
+
+/// Returns the prime factorization of `n`, as each prime factor with its
+/// multiplicity, in increasing order of prime, using trial division.
+///
+/// Divide out each factor d, from 2 upwards, as many times as it divides n.
+/// Every d that divides what's left of n is prime, since its own smaller
+/// factors were already divided out. Once d passes the square root of what's
+/// left, that remainder has no factor below its square root, so it's either
+/// 1 or prime.
+///
+/// 0 has no prime factorization and 1 has none, so both return an empty
+/// `Vec`.
+///
+/// Worst-case performance: O(sqrt(n)).
+/// Worst-case space complexity: O(log n) auxiliary.
+pub fn prime_factors(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+
+    // `d <= n / d` rather than `d * d <= n`, which can overflow.
+    let mut d = 2;
+    while d <= n / d {
+        let mut multiplicity = 0;
+        while n.is_multiple_of(d) {
+            n /= d;
+            multiplicity += 1;
+        }
+        if multiplicity > 0 {
+            factors.push((d, multiplicity));
+        }
+        // After 2, only odd numbers can be prime.
+        d += if d == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sieve() {
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], sieve(30));
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31], sieve(31));
+        assert_eq!(vec![2, 3], sieve(3));
+        assert_eq!(vec![2], sieve(2));
+        assert!(sieve(1).is_empty());
+        assert!(sieve(0).is_empty());
+
+        // Perfect squares of primes must be crossed off.
+        assert!(!sieve(49).contains(&49));
+        assert_eq!(1229, sieve(10_000).len());
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let primes = sieve(1000);
+        for n in 0..=1000 {
+            assert_eq!(primes.contains(&(n as usize)), is_prime(n), "{n}");
+        }
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(1_000_000_007 * 3));
+        assert!(is_prime(999_999_999_989));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], prime_factors(360));
+        assert_eq!(vec![(7, 1)], prime_factors(7));
+        assert_eq!(vec![(2, 10)], prime_factors(1024));
+        assert_eq!(vec![(3, 4)], prime_factors(81));
+        assert_eq!(vec![(2, 1), (3, 1), (5, 1), (7, 1), (11, 1), (13, 1)], prime_factors(30030));
+        assert_eq!(vec![(2, 1)], prime_factors(2));
+        assert!(prime_factors(1).is_empty());
+        assert!(prime_factors(0).is_empty());
+
+        // The prime left over after trial division.
+        assert_eq!(vec![(2, 1), (1_000_000_007, 1)], prime_factors(2_000_000_014));
+        assert_eq!(vec![(999_999_999_989, 1)], prime_factors(999_999_999_989));
+        assert_eq!(
+            vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6_700_417, 1)],
+            prime_factors(u64::MAX)
+        );
+
+        for n in 2..1000 {
+            let product: u64 = prime_factors(n).iter().map(|&(p, k)| p.pow(k)).product();
+            assert_eq!(n, product);
+            assert!(prime_factors(n).iter().all(|&(p, _)| is_prime(p)));
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! # queue data structures.
+//!
+//! `queue` defines various queue data structures on generic types.
+
+/// A fixed-capacity first-in, first-out queue backed by a ring buffer.
+///
+/// Elements are enqueued at the tail index and dequeued from the head index,
+/// both of which advance and wrap around to the start of the buffer once they
+/// reach the end. Elements never move once enqueued, so no shifting is needed
+/// when the head advances.
+///
+/// Enqueue: O(1).
+/// Dequeue: O(1).
+#[derive(Debug, Clone)]
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    // The index of the next element to dequeue.
+    head: usize,
+    // The index of the next slot to enqueue into.
+    tail: usize,
+    // The number of occupied slots, needed since `head == tail` both when empty
+    // and when full.
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates an empty queue that can hold `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut slots = Vec::with_capacity(capacity);
+        slots.resize_with(capacity, || None);
+        Self { slots, head: 0, tail: 0, len: 0 }
+    }
+
+    /// Enqueues `item` at the back of the queue.
+    ///
+    /// Returns `Err` with `item` if the queue is full.
+    pub fn enqueue(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.slots[self.tail] = Some(item);
+        self.tail = (self.tail + 1) % self.capacity();
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the front element, or `None` if the queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        item
+    }
+
+    /// Returns the front element, or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.slots.get(self.head).and_then(Option::as_ref)
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of elements the queue can hold.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the queue can't hold any more elements.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer() {
+        let mut queue = RingBuffer::with_capacity(3);
+        assert!(queue.is_empty());
+        assert_eq!(None, queue.dequeue());
+
+        assert_eq!(Ok(()), queue.enqueue(1));
+        assert_eq!(Ok(()), queue.enqueue(2));
+        assert_eq!(Ok(()), queue.enqueue(3));
+        assert!(queue.is_full());
+        assert_eq!(Err(4), queue.enqueue(4));
+
+        assert_eq!(Some(1), queue.dequeue());
+        assert!(!queue.is_full());
+
+        // The tail wraps around to the slot freed by the dequeue.
+        assert_eq!(Ok(()), queue.enqueue(4));
+        assert!(queue.is_full());
+        assert_eq!(Some(&2), queue.peek());
+        assert_eq!(Some(2), queue.dequeue());
+        assert_eq!(Some(3), queue.dequeue());
+        assert_eq!(Some(4), queue.dequeue());
+        assert_eq!(None, queue.dequeue());
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_ring_buffer_wraparound() {
+        let mut queue = RingBuffer::with_capacity(4);
+        let mut next = 0;
+        let mut expected = 0;
+        for round in 0..20 {
+            while queue.enqueue(next).is_ok() {
+                next += 1;
+            }
+            for _ in 0..(round % 4) + 1 {
+                assert_eq!(Some(expected), queue.dequeue());
+                expected += 1;
+            }
+        }
+        assert_eq!(next - expected, queue.len());
+    }
+
+    #[test]
+    fn test_ring_buffer_zero_capacity() {
+        let mut queue = RingBuffer::with_capacity(0);
+        assert!(queue.is_full() && queue.is_empty());
+        assert_eq!(Err("a"), queue.enqueue("a"));
+        assert_eq!(None, queue.dequeue());
+        assert_eq!(None, queue.peek());
+    }
+}
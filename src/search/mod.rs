@@ -1 +1,342 @@
+//! # search algorithms.
+//!
+//! `search` defines various searching algorithms on generic types.
 
+use std::cmp::Ordering;
+
+/// Searches a sorted list for `target` using binary search, returning the index
+/// of a matching element.
+///
+/// Compare the target against the middle element of the list. If equal, the
+/// search is done. If smaller, the target can only be in the lhs half, if
+/// larger, only in the rhs half, so repeat on that half. Stop once the half is
+/// empty.
+///
+/// The midpoint is computed as `lo + (hi - lo) / 2` since `(lo + hi) / 2` can
+/// overflow for large indices.
+///
+/// If there are several matches, any one of them may be returned.
+///
+/// Worst-case performance: O(log n).
+/// Best-case performance: O(1).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn binary_search<T: Ord>(list: &[T], target: &T) -> Option<usize> {
+    // Search the half-open range `lo..hi`.
+    let (mut lo, mut hi) = (0, list.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match list[mid].cmp(target) {
+            Ordering::Equal => return Some(mid),
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+        }
+    }
+    None
+}
+
+/// Searches a sorted list for `target` using binary search, returning the index
+/// of the first matching element.
+///
+/// Rather than stopping at the first match, keep narrowing the range to the
+/// first element that is not less than the target, then check that it
+/// matches.
+///
+/// Worst-case performance: O(log n).
+/// Best-case performance: O(log n).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn binary_search_leftmost<T: Ord>(list: &[T], target: &T) -> Option<usize> {
+    let i = partition_point(list, |x| x < target);
+    (i < list.len() && list[i] == *target).then_some(i)
+}
+
+/// Searches a sorted list for `target` using binary search, returning the index
+/// of the last matching element.
+///
+/// Rather than stopping at the first match, keep narrowing the range to the
+/// first element that is greater than the target, then check that the element
+/// before it matches.
+///
+/// Worst-case performance: O(log n).
+/// Best-case performance: O(log n).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn binary_search_rightmost<T: Ord>(list: &[T], target: &T) -> Option<usize> {
+    let i = partition_point(list, |x| x <= target);
+    (i > 0 && list[i - 1] == *target).then(|| i - 1)
+}
+
+/// Searches a sorted list for `target` using exponential search, returning the
+/// index of a matching element.
+///
+/// Double a bound, starting from 1, until the element at the bound is no
+/// longer less than the target, or the bound passes the end of the list. The
+/// target can then only lie between the previous bound and this one, so binary
+/// search that window.
+///
+/// Exponential search is O(log i), for a target at index i, so it beats plain
+/// binary search when the target is likely near the start of a large list.
+///
+/// Worst-case performance: O(log i).
+/// Best-case performance: O(1).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn exponential_search<T: Ord>(list: &[T], target: &T) -> Option<usize> {
+    if list.is_empty() {
+        return None;
+    }
+
+    let mut bound = 1;
+    while bound < list.len() && list[bound] < *target {
+        bound *= 2;
+    }
+
+    // The previous bound was less than the target, so start just after it.
+    let lo = bound / 2;
+    let hi = (bound + 1).min(list.len());
+    binary_search(&list[lo..hi], target).map(|i| lo + i)
+}
+
+/// Searches a sorted list for `target` using jump search, returning the index
+/// of the first matching element.
+///
+/// Jump through the list in blocks of sqrt(n) elements, checking the last
+/// element of each block, until reaching a block whose last element is not
+/// less than the target. The target can then only be in that block, so scan
+/// it linearly.
+///
+/// A block size of sqrt(n) balances the at most sqrt(n) jumps against the at
+/// most sqrt(n) steps of the scan. Jump search only steps forwards, plus at
+/// most one block back, which suits storage where seeking backwards is
+/// expensive.
+///
+/// `list` must be sorted, otherwise the result is unspecified.
+///
+/// Worst-case performance: O(sqrt n).
+/// Best-case performance: O(1).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn jump_search<T: Ord>(list: &[T], target: &T) -> Option<usize> {
+    let len = list.len();
+    let step = len.isqrt().max(1);
+
+    // Find the block `start..end` that must contain the target, if any.
+    let (mut start, mut end) = (0, step.min(len));
+    while end < len && list[end - 1] < *target {
+        start = end;
+        end = (end + step).min(len);
+    }
+
+    let i = start + list[start..end].iter().take_while(|&x| x < target).count();
+    (i < end && list[i] == *target).then_some(i)
+}
+
+/// Searches a sorted list for `target` using interpolation search, returning
+/// the index of a matching element.
+///
+/// Like binary search, but rather than probing the middle of the range,
+/// estimate where the target lies by linear interpolation between the values
+/// at either end of it, the way one might open a dictionary near the back for
+/// a word starting with "w". Repeat on whichever side of the probe the target
+/// must be in, until the target is found or falls outside the values of the
+/// range.
+///
+/// On uniformly distributed values the estimate is close, so the range shrinks
+/// very quickly. On skewed values, e.g. exponentially growing ones, the
+/// estimate can be poor every time, and each probe only peels off a single
+/// element.
+///
+/// If there are several matches, any one of them may be returned.
+///
+/// Worst-case performance: O(n).
+/// Average performance: O(log log n), for uniformly distributed values.
+/// Best-case performance: O(1).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn interpolation_search(list: &[i64], target: i64) -> Option<usize> {
+    if list.is_empty() {
+        return None;
+    }
+
+    // Search the closed range `lo..=hi`.
+    let (mut lo, mut hi) = (0, list.len() - 1);
+    while lo <= hi && list[lo] <= target && target <= list[hi] {
+        // Every value in the range is equal, so there's nothing to
+        // interpolate, and dividing by their difference would be by zero.
+        if list[lo] == list[hi] {
+            return (list[lo] == target).then_some(lo);
+        }
+
+        // Widen to `i128` since the differences of `i64` values can overflow.
+        let offset = (target as i128 - list[lo] as i128) * (hi - lo) as i128
+            / (list[hi] as i128 - list[lo] as i128);
+        // The target is within the values of the range, so the offset is too,
+        // but clamp it anyway in case of rounding.
+        let probe = lo + (offset as usize).min(hi - lo);
+
+        match list[probe].cmp(&target) {
+            Ordering::Equal => return Some(probe),
+            Ordering::Less => lo = probe + 1,
+            // `list[lo]` is no greater than the target, so `probe > lo`.
+            Ordering::Greater => hi = probe - 1,
+        }
+    }
+    None
+}
+
+/// Returns the index of the first element for which `pred` is false, given
+/// that `pred` is true for some prefix of `list` and false for the rest.
+pub(crate) fn partition_point<T, P: Fn(&T) -> bool>(list: &[T], pred: P) -> usize {
+    let (mut lo, mut hi) = (0, list.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&list[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_binary_search() {
+        let list1 = &[1, 3, 5, 7, 9, 11];
+        assert_eq!(Some(2), binary_search(list1, &5));
+        assert_eq!(Some(0), binary_search(list1, &1));
+        assert_eq!(Some(5), binary_search(list1, &11));
+        assert_eq!(None, binary_search(list1, &4));
+        assert_eq!(None, binary_search(list1, &0));
+        assert_eq!(None, binary_search(list1, &12));
+
+        let list2: &[i32] = &[];
+        assert_eq!(None, binary_search(list2, &1));
+
+        let list3 = &[42];
+        assert_eq!(Some(0), binary_search(list3, &42));
+        assert_eq!(None, binary_search(list3, &41));
+
+        let list4 = &['a', 'b', 'c'];
+        assert_eq!(Some(1), binary_search(list4, &'b'));
+
+        let list5 = &["A new day", "A old day", "Test"];
+        assert_eq!(Some(2), binary_search(list5, &"Test"));
+    }
+
+    #[test]
+    fn test_binary_search_leftmost() {
+        let list1 = &[1, 2, 2, 2, 3];
+        assert_eq!(Some(1), binary_search_leftmost(list1, &2));
+        assert_eq!(Some(0), binary_search_leftmost(list1, &1));
+        assert_eq!(Some(4), binary_search_leftmost(list1, &3));
+        assert_eq!(None, binary_search_leftmost(list1, &0));
+        assert_eq!(None, binary_search_leftmost(list1, &4));
+
+        let list2 = &[7, 7, 7];
+        assert_eq!(Some(0), binary_search_leftmost(list2, &7));
+
+        let list3: &[i32] = &[];
+        assert_eq!(None, binary_search_leftmost(list3, &1));
+    }
+
+    #[test]
+    fn test_binary_search_rightmost() {
+        let list1 = &[1, 2, 2, 2, 3];
+        assert_eq!(Some(3), binary_search_rightmost(list1, &2));
+        assert_eq!(Some(0), binary_search_rightmost(list1, &1));
+        assert_eq!(Some(4), binary_search_rightmost(list1, &3));
+        assert_eq!(None, binary_search_rightmost(list1, &0));
+        assert_eq!(None, binary_search_rightmost(list1, &4));
+
+        let list2 = &[7, 7, 7];
+        assert_eq!(Some(2), binary_search_rightmost(list2, &7));
+
+        let list3: &[i32] = &[];
+        assert_eq!(None, binary_search_rightmost(list3, &1));
+    }
+
+    #[test]
+    fn test_exponential_search() {
+        let list1: &Vec<i32> = &(0..1000).map(|i| i * 2).collect();
+        assert_eq!(Some(0), exponential_search(list1, &0));
+        assert_eq!(Some(1), exponential_search(list1, &2));
+        assert_eq!(Some(500), exponential_search(list1, &1000));
+        assert_eq!(Some(999), exponential_search(list1, &1998));
+        assert_eq!(None, exponential_search(list1, &1001));
+        assert_eq!(None, exponential_search(list1, &-1));
+        assert_eq!(None, exponential_search(list1, &2000));
+        for (i, x) in list1.iter().enumerate() {
+            assert_eq!(Some(i), exponential_search(list1, x));
+        }
+
+        let list2: &[i32] = &[];
+        assert_eq!(None, exponential_search(list2, &1));
+
+        let list3 = &[42];
+        assert_eq!(Some(0), exponential_search(list3, &42));
+        assert_eq!(None, exponential_search(list3, &43));
+    }
+
+    #[test]
+    fn test_interpolation_search() {
+        let list1: &Vec<i64> = &(0..1000).map(|i| i * 5).collect();
+        assert_eq!(Some(0), interpolation_search(list1, 0));
+        assert_eq!(Some(71), interpolation_search(list1, 355));
+        assert_eq!(Some(999), interpolation_search(list1, 4995));
+        assert_eq!(None, interpolation_search(list1, 356));
+        assert_eq!(None, interpolation_search(list1, -5));
+        assert_eq!(None, interpolation_search(list1, 5000));
+        for (i, &x) in list1.iter().enumerate() {
+            assert_eq!(Some(i), interpolation_search(list1, x));
+        }
+
+        // All equal values must not divide by zero.
+        let list2 = &[7; 10];
+        assert_eq!(Some(0), interpolation_search(list2, 7));
+        assert_eq!(None, interpolation_search(list2, 6));
+        assert_eq!(None, interpolation_search(list2, 8));
+
+        // Skewed values, and extremes whose difference overflows `i64`.
+        let list3 = &[i64::MIN, -1000, 0, 1, 2, 4, 8, 1 << 40, i64::MAX];
+        for (i, &x) in list3.iter().enumerate() {
+            assert_eq!(Some(i), interpolation_search(list3, x));
+        }
+        assert_eq!(None, interpolation_search(list3, 3));
+        assert_eq!(None, interpolation_search(list3, i64::MAX - 1));
+
+        let list4: &[i64] = &[];
+        assert_eq!(None, interpolation_search(list4, 1));
+
+        let list5 = &[42];
+        assert_eq!(Some(0), interpolation_search(list5, 42));
+        assert_eq!(None, interpolation_search(list5, 41));
+    }
+
+    #[test]
+    fn test_jump_search() {
+        let list1: &Vec<i32> = &(0..100).map(|i| i * 2).collect();
+        assert_eq!(Some(0), jump_search(list1, &0));
+        assert_eq!(Some(37), jump_search(list1, &74));
+        assert_eq!(None, jump_search(list1, &75));
+        assert_eq!(None, jump_search(list1, &-1));
+        assert_eq!(None, jump_search(list1, &200));
+        for (i, x) in list1.iter().enumerate() {
+            assert_eq!(Some(i), jump_search(list1, x));
+        }
+
+        // 10 isn't a perfect square, so the last block is short.
+        let list2 = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(Some(9), jump_search(list2, &10));
+        assert_eq!(Some(8), jump_search(list2, &9));
+        assert_eq!(None, jump_search(list2, &11));
+
+        let list3 = &[1, 2, 2, 2, 3];
+        assert_eq!(Some(1), jump_search(list3, &2));
+
+        let list4: &[i32] = &[];
+        assert_eq!(None, jump_search(list4, &1));
+
+        let list5 = &["A new day", "A old day", "Test"];
+        assert_eq!(Some(2), jump_search(list5, &"Test"));
+        assert_eq!(None, jump_search(list5, &"Tests"));
+    }
+}
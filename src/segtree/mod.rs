@@ -0,0 +1,194 @@
+//! # segment tree data structures.
+//!
+//! `segtree` defines a segment tree for range queries over a list of generic
+//! values.
+
+use std::ops::Add;
+
+/// A list of values supporting point updates and range queries, combining
+/// the values in a range with `merge`, e.g. their sum or minimum.
+///
+/// `merge` must be associative, i.e. `merge(merge(a, b), c)` equals
+/// `merge(a, merge(b, c))`, but needn't be commutative.
+///
+/// The tree is a complete binary tree stored level by level in a `Vec` of
+/// `2n` nodes, with the values as leaves at `n..2n`, and the node at index
+/// `i` holding the merge of its children at `2i` and `2i + 1`. A query climbs
+/// from both ends of the range towards the root, merging in the nodes that
+/// cover part of the range without overhanging it, of which there are at
+/// most two per level. An update sets a leaf and recomputes its ancestors.
+///
+/// Update: O(log n).
+/// Query: O(log n).
+#[derive(Debug, Clone)]
+pub struct SegmentTree<T> {
+    /// The nodes, with `nodes[0]` unused, and the leaves from `nodes[len]` on.
+    nodes: Vec<T>,
+    len: usize,
+    merge: fn(&T, &T) -> T,
+}
+
+impl<T: Clone> SegmentTree<T> {
+    /// Creates a tree of the values in `values`, combined with `merge`.
+    pub fn build(values: &[T], merge: fn(&T, &T) -> T) -> Self {
+        let len = values.len();
+        // Every internal node is overwritten below, so fill them with any
+        // value to start with.
+        let mut nodes = match values.first() {
+            Some(first) => vec![first.clone(); len],
+            None => Vec::new(),
+        };
+        nodes.extend_from_slice(values);
+        for i in (1..len).rev() {
+            nodes[i] = merge(&nodes[2 * i], &nodes[2 * i + 1]);
+        }
+        Self { nodes, len, merge }
+    }
+
+    /// Creates a tree of the values in `values`, queried for range sums.
+    pub fn new_sum(values: &[T]) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self::build(values, |a, b| a.clone() + b.clone())
+    }
+
+    /// Creates a tree of the values in `values`, queried for range minimums.
+    pub fn new_min(values: &[T]) -> Self
+    where
+        T: Ord,
+    {
+        Self::build(values, |a, b| a.min(b).clone())
+    }
+
+    /// Sets the value at `i` to `value`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn update(&mut self, i: usize, value: T) {
+        assert!(i < self.len, "index {i} is out of bounds");
+        let mut i = i + self.len;
+        self.nodes[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.nodes[i] = (self.merge)(&self.nodes[2 * i], &self.nodes[2 * i + 1]);
+        }
+    }
+
+    /// Returns the merge of the values at `l..=r`.
+    ///
+    /// Panics if `l > r` or `r` is out of bounds.
+    pub fn query(&self, l: usize, r: usize) -> T {
+        assert!(l <= r, "range {l}..={r} is reversed");
+        assert!(r < self.len, "index {r} is out of bounds");
+
+        // The merges of the nodes taken from the lhs and rhs ends, kept apart
+        // so the values are merged in order.
+        let (mut left, mut right): (Option<T>, Option<T>) = (None, None);
+        // Climb the half-open range of nodes `l..r`.
+        let (mut l, mut r) = (l + self.len, r + 1 + self.len);
+        while l < r {
+            // An odd lhs node is a rhs child, whose parent overhangs the range.
+            if l % 2 == 1 {
+                left = Some(match left {
+                    Some(left) => (self.merge)(&left, &self.nodes[l]),
+                    None => self.nodes[l].clone(),
+                });
+                l += 1;
+            }
+            // Likewise an odd rhs bound excludes a rhs child whose lhs sibling
+            // is in the range.
+            if r % 2 == 1 {
+                r -= 1;
+                right = Some(match right {
+                    Some(right) => (self.merge)(&self.nodes[r], &right),
+                    None => self.nodes[r].clone(),
+                });
+            }
+            l /= 2;
+            r /= 2;
+        }
+
+        // Safe to unwrap, the range is non-empty so at least one node was taken.
+        match (left, right) {
+            (Some(left), Some(right)) => (self.merge)(&left, &right),
+            (left, right) => left.or(right).unwrap(),
+        }
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_segment_tree_sum() {
+        let mut tree = SegmentTree::new_sum(&[5, 8, 6, 3, 2, 7, 2, 6]);
+        assert_eq!(8, tree.len());
+        assert_eq!(39, tree.query(0, 7));
+        assert_eq!(17, tree.query(1, 3));
+        assert_eq!(6, tree.query(2, 2));
+        assert_eq!(6, tree.query(7, 7));
+
+        tree.update(2, 10);
+        assert_eq!(43, tree.query(0, 7));
+        assert_eq!(21, tree.query(1, 3));
+        assert_eq!(10, tree.query(2, 2));
+    }
+
+    #[test]
+    fn test_segment_tree_min() {
+        // Not a power of two, so the leaves don't all sit on one level.
+        let mut tree = SegmentTree::new_min(&[5, 8, 6, 3, 9, 7, 2]);
+        assert_eq!(2, tree.query(0, 6));
+        assert_eq!(5, tree.query(0, 2));
+        assert_eq!(3, tree.query(1, 4));
+        assert_eq!(9, tree.query(4, 4));
+
+        tree.update(3, 10);
+        assert_eq!(6, tree.query(1, 4));
+        tree.update(6, 4);
+        assert_eq!(4, tree.query(0, 6));
+    }
+
+    #[test]
+    fn test_segment_tree_all_ranges() {
+        let values: Vec<i64> = (0..37).map(|i| (i * 37) % 11 - 5).collect();
+        let sums = SegmentTree::new_sum(&values);
+        let mins = SegmentTree::new_min(&values);
+        for l in 0..values.len() {
+            for r in l..values.len() {
+                assert_eq!(values[l..=r].iter().sum::<i64>(), sums.query(l, r));
+                assert_eq!(values[l..=r].iter().min().copied().unwrap(), mins.query(l, r));
+            }
+        }
+    }
+
+    #[test]
+    fn test_segment_tree_non_commutative() {
+        // Concatenation is associative but not commutative, so the order of
+        // each query's merges shows.
+        let words: Vec<String> = "the quick brown fox jumps".split(' ').map(String::from).collect();
+        let mut tree = SegmentTree::build(&words, |a, b| format!("{a} {b}"));
+        assert_eq!("the quick brown fox jumps", tree.query(0, 4));
+        assert_eq!("quick brown fox", tree.query(1, 3));
+
+        tree.update(2, String::from("red"));
+        assert_eq!("quick red fox", tree.query(1, 3));
+    }
+
+    #[test]
+    fn test_segment_tree_empty() {
+        let tree: SegmentTree<i32> = SegmentTree::new_sum(&[]);
+        assert!(tree.is_empty());
+    }
+}
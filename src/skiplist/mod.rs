@@ -0,0 +1,295 @@
+//! # skip list data structures.
+//!
+//! `skiplist` defines an ordered set backed by a skip list.
+
+use std::cmp::Ordering;
+
+/// Marks the end of a level, or the head when used as a predecessor.
+const NIL: usize = usize::MAX;
+
+/// The most levels a node can be linked into.
+const MAX_LEVEL: usize = 32;
+
+/// The seed [`SkipList::new`] draws levels from.
+const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// An ordered set backed by a skip list.
+///
+/// A skip list is a stack of sorted linked lists, or levels. The bottom level
+/// links every node, and each level above links a random subset of the one
+/// below, each node being promoted with probability 1/2. To search, walk along
+/// the top level until the next node would overshoot, then drop down a level
+/// and continue, so each level skips over about half of the nodes the one
+/// below it visits.
+///
+/// The nodes live in a `Vec`, linked by index like
+/// [`LruCache`](crate::cache::lru::LruCache). Removing a node moves the last
+/// node into its slot, so slots are never left empty.
+///
+/// The levels are drawn from a pseudo-random generator, seeded with
+/// [`SkipList::with_seed`] for reproducible structure.
+///
+/// Insert: O(log n) expected.
+/// Contains: O(log n) expected.
+/// Remove: O(log n) expected.
+#[derive(Debug, Clone)]
+pub struct SkipList<T: Ord> {
+    nodes: Vec<Node<T>>,
+    // The index of the first node in each level, bottom up.
+    head: Vec<usize>,
+    // The splitmix64 generator state.
+    state: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    // The index of the next node in each level this node is linked into.
+    next: Vec<usize>,
+}
+
+impl<T: Ord> SkipList<T> {
+    /// Creates an empty skip list.
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    /// Creates an empty skip list drawing levels from a generator seeded with
+    /// `seed`.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { nodes: Vec::new(), head: Vec::new(), state: seed }
+    }
+
+    /// Inserts `value` into the skip list.
+    ///
+    /// Returns `false` if the skip list already contained an equal value, in
+    /// which case the skip list is unchanged.
+    pub fn insert(&mut self, value: T) -> bool {
+        let preds = self.predecessors(&value);
+        if self.find(&preds, &value).is_some() {
+            return false;
+        }
+
+        let level = self.random_level();
+        if self.head.len() < level {
+            self.head.resize(level, NIL);
+        }
+
+        // Levels the skip list didn't have before start at the head.
+        let i = self.nodes.len();
+        let mut next = Vec::with_capacity(level);
+        for l in 0..level {
+            let pred = preds.get(l).copied().unwrap_or(NIL);
+            next.push(self.next(pred, l));
+            self.set_next(pred, l, i);
+        }
+        self.nodes.push(Node { value, next });
+        true
+    }
+
+    /// Returns `true` if the skip list contains `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(&self.predecessors(value), value).is_some()
+    }
+
+    /// Removes `value` from the skip list.
+    ///
+    /// Returns `false` if the skip list didn't contain `value`.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let preds = self.predecessors(value);
+        let Some(i) = self.find(&preds, value) else {
+            return false;
+        };
+
+        for (l, &pred) in preds.iter().enumerate().take(self.nodes[i].next.len()) {
+            let next = self.nodes[i].next[l];
+            self.set_next(pred, l, next);
+        }
+        while self.head.last() == Some(&NIL) {
+            self.head.pop();
+        }
+
+        // Move the last node into the freed slot, relinking its predecessors.
+        let last = self.nodes.len() - 1;
+        if i != last {
+            let preds = self.predecessors(&self.nodes[last].value);
+            for (l, &pred) in preds.iter().enumerate().take(self.nodes[last].next.len()) {
+                self.set_next(pred, l, i);
+            }
+        }
+        self.nodes.swap_remove(i);
+        true
+    }
+
+    /// Returns the number of values in the skip list.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the skip list contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over the values in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self, next: self.head.first().copied().unwrap_or(NIL) }
+    }
+
+    /// Returns the last node before `value` in each level, bottom up, or
+    /// [`NIL`] where that's the head.
+    fn predecessors(&self, value: &T) -> Vec<usize> {
+        let mut preds = vec![NIL; self.head.len()];
+        let mut node = NIL;
+        for l in (0..self.head.len()).rev() {
+            loop {
+                let next = self.next(node, l);
+                if next == NIL || self.nodes[next].value.cmp(value) != Ordering::Less {
+                    break;
+                }
+                node = next;
+            }
+            preds[l] = node;
+        }
+        preds
+    }
+
+    /// Returns the index of the node holding `value`, given its predecessors.
+    fn find(&self, preds: &[usize], value: &T) -> Option<usize> {
+        let next = self.next(*preds.first()?, 0);
+        (next != NIL && self.nodes[next].value == *value).then_some(next)
+    }
+
+    /// Returns the node after `node` in level `l`, where [`NIL`] is the head.
+    fn next(&self, node: usize, l: usize) -> usize {
+        match node {
+            NIL => self.head[l],
+            node => self.nodes[node].next[l],
+        }
+    }
+
+    /// Links `to` after `node` in level `l`, where [`NIL`] is the head.
+    fn set_next(&mut self, node: usize, l: usize, to: usize) {
+        match node {
+            NIL => self.head[l] = to,
+            node => self.nodes[node].next[l] = to,
+        }
+    }
+
+    /// Returns the number of levels to link a new node into, i.e. 1, plus 1
+    /// more with probability 1/2, plus 1 more with probability 1/4, and so on.
+    fn random_level(&mut self) -> usize {
+        // splitmix64, which unlike xorshift has no bad seeds.
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        // Each trailing one bit is a coin flip that came up heads.
+        (z.trailing_ones() as usize + 1).min(MAX_LEVEL)
+    }
+}
+
+impl<T: Ord> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the values of a [`SkipList`], in ascending order.
+pub struct Iter<'a, T: Ord> {
+    list: &'a SkipList<T>,
+    next: usize,
+}
+
+impl<'a, T: Ord> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == NIL {
+            return None;
+        }
+        let node = &self.list.nodes[self.next];
+        self.next = node.next[0];
+        Some(&node.value)
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SkipList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns `0..n` shuffled by a fixed-seed Fisher-Yates shuffle.
+    fn shuffled(n: u32) -> Vec<u32> {
+        let mut values: Vec<u32> = (0..n).collect();
+        let mut state: u32 = 0x2545_f491;
+        for i in (1..values.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            values.swap(i, state as usize % (i + 1));
+        }
+        values
+    }
+
+    #[test]
+    fn test_skip_list() {
+        let mut list = SkipList::new();
+        assert!(list.is_empty());
+        assert!(list.insert(5));
+        assert!(list.insert(1));
+        assert!(list.insert(3));
+        assert!(!list.insert(3));
+        assert_eq!(3, list.len());
+
+        assert!(list.contains(&1));
+        assert!(!list.contains(&2));
+        assert_eq!(vec![&1, &3, &5], list.iter().collect::<Vec<_>>());
+
+        assert!(list.remove(&3));
+        assert!(!list.remove(&3));
+        assert!(!list.contains(&3));
+        assert_eq!(vec![&1, &5], list.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_skip_list_shuffled() {
+        let values = shuffled(10_000);
+        let mut list = SkipList::with_seed(42);
+        for &value in &values {
+            assert!(list.insert(value));
+        }
+        assert_eq!(10_000, list.len());
+        for value in &values {
+            assert!(list.contains(value));
+        }
+        assert!(!list.contains(&10_000));
+        assert!(list.iter().copied().eq(0..10_000));
+
+        // Remove the odd values, in shuffled order.
+        for value in values.iter().filter(|&v| v % 2 == 1) {
+            assert!(list.remove(value));
+        }
+        assert_eq!(5000, list.len());
+        for value in &values {
+            assert_eq!(value % 2 == 0, list.contains(value));
+        }
+        assert!(list.iter().copied().eq((0..10_000).step_by(2)));
+
+        for value in &values {
+            list.remove(value);
+        }
+        assert!(list.is_empty());
+        assert_eq!(None, list.iter().next());
+    }
+}
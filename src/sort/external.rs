@@ -0,0 +1,187 @@
+//! # external sort.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Lines, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{self, AtomicUsize};
+
+use crate::heap::BinaryHeap;
+use crate::sort::merge_sort;
+
+/// Numbers the runs written by this process, so concurrent sorts don't share
+/// files.
+static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+
+/// Sorts the newline-delimited integers in the file at `input` into the file at
+/// `output`, using external merge sort, holding at most `max_in_memory` lines
+/// in memory while sorting.
+///
+/// Read the input a chunk of `max_in_memory` lines at a time, sort each chunk
+/// in memory using [`merge_sort`], and write it to a temporary file as a sorted
+/// run. Then k-way merge the runs into the output. Keep the smallest unmerged
+/// value of each run on a min-heap, and repeatedly pop the smallest, write it
+/// out and push the next value from the same run. While merging only one
+/// value per run is held in memory.
+///
+/// The runs are written to [`std::env::temp_dir`], and removed once the sort
+/// finishes or fails.
+///
+/// Blank lines are skipped. Returns an error of kind
+/// [`io::ErrorKind::InvalidData`] if any other line isn't an `i64`.
+///
+/// Panics if `max_in_memory` is 0.
+///
+/// Worst-case performance: O(n log n).
+/// Worst-case space complexity: O(m + n / m) auxiliary in memory, O(n) on
+/// disk, for m = `max_in_memory`.
+pub fn external_merge_sort(input: &Path, output: &Path, max_in_memory: usize) -> io::Result<()> {
+    assert!(max_in_memory > 0, "max_in_memory must be at least 1");
+
+    let mut runs = Runs(Vec::new());
+    let mut chunk = Vec::with_capacity(max_in_memory);
+    let mut lines = BufReader::new(File::open(input)?).lines();
+    loop {
+        let value = next_value(&mut lines)?;
+        if let Some(value) = value {
+            chunk.push(value);
+        }
+
+        let done = value.is_none();
+        if chunk.len() == max_in_memory || (done && !chunk.is_empty()) {
+            merge_sort(&mut chunk);
+            runs.write(&chunk)?;
+            chunk.clear();
+        }
+        if done {
+            break;
+        }
+    }
+
+    merge_runs(&runs.0, output)
+}
+
+/// The sorted runs written to disk, removed when dropped.
+struct Runs(Vec<PathBuf>);
+
+impl Runs {
+    /// Writes `values` to a new run.
+    fn write(&mut self, values: &[i64]) -> io::Result<()> {
+        let run = NEXT_RUN.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("dsa-sort-{}-{run}.run", process::id()));
+        // Push first, so the run is removed even if writing it fails.
+        self.0.push(path.clone());
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        for value in values {
+            writeln!(writer, "{value}")?;
+        }
+        writer.flush()
+    }
+}
+
+impl Drop for Runs {
+    fn drop(&mut self) {
+        for path in &self.0 {
+            // Nothing to be done if a run can't be removed, it's only litter.
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Merges the sorted runs at `paths` into the file at `output`.
+fn merge_runs(paths: &[PathBuf], output: &Path) -> io::Result<()> {
+    let mut runs = paths
+        .iter()
+        .map(|path| Ok(BufReader::new(File::open(path)?).lines()))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    // Holds the smallest unmerged value of each run, with the run's index.
+    let mut heap = BinaryHeap::new_min();
+    for (i, run) in runs.iter_mut().enumerate() {
+        if let Some(value) = next_value(run)? {
+            heap.push((value, i));
+        }
+    }
+
+    let mut writer = BufWriter::new(File::create(output)?);
+    while let Some((value, i)) = heap.pop() {
+        writeln!(writer, "{value}")?;
+        if let Some(next) = next_value(&mut runs[i])? {
+            heap.push((next, i));
+        }
+    }
+    writer.flush()
+}
+
+/// Returns the next integer from `lines`, skipping blank lines, or `None` once
+/// `lines` is exhausted.
+fn next_value(lines: &mut Lines<BufReader<File>>) -> io::Result<Option<i64>> {
+    for line in lines {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        return line
+            .parse()
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{line:?}: {e}")));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns a path in the temporary directory unique to this process.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("dsa-sort-test-{}-{name}", process::id()))
+    }
+
+    #[test]
+    fn test_external_merge_sort() {
+        let n = 100_000;
+        let (input, output) = (temp_path("input"), temp_path("output"));
+
+        // 7919 is prime, so coprime with n, and this is a permutation of 0..n.
+        let shuffled: Vec<String> = (0..n).map(|i| ((i * 7919) % n - n / 2).to_string()).collect();
+        fs::write(&input, shuffled.join("\n") + "\n\n").unwrap();
+
+        external_merge_sort(&input, &output, 1000).unwrap();
+        let sorted: Vec<i64> = fs::read_to_string(&output)
+            .unwrap()
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect();
+        assert_eq!((-n / 2..n / 2).collect::<Vec<_>>(), sorted);
+
+        fs::remove_file(&input).unwrap();
+        fs::remove_file(&output).unwrap();
+    }
+
+    #[test]
+    fn test_external_merge_sort_empty() {
+        let (input, output) = (temp_path("empty-input"), temp_path("empty-output"));
+        fs::write(&input, "").unwrap();
+
+        external_merge_sort(&input, &output, 1).unwrap();
+        assert_eq!("", fs::read_to_string(&output).unwrap());
+
+        fs::remove_file(&input).unwrap();
+        fs::remove_file(&output).unwrap();
+    }
+
+    #[test]
+    fn test_external_merge_sort_invalid() {
+        let (input, output) = (temp_path("invalid-input"), temp_path("invalid-output"));
+        fs::write(&input, "3\n1\nx\n2\n").unwrap();
+
+        let error = external_merge_sort(&input, &output, 2).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert!(!output.exists());
+
+        fs::remove_file(&input).unwrap();
+    }
+}
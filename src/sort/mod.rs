@@ -1,6 +1,50 @@
 //! # sort algorithms.
 //!
 //! `sort` defines various sorting algorithms on generic types.
+//!
+//! A sort is stable if elements that compare equal keep their original
+//! relative order. Bubble sort, cocktail shaker sort, insertion sort, binary
+//! insertion sort, gnome sort and every merge sort variant, including natural
+//! merge sort, are stable. Comb sort, selection sort, cycle sort, pancake sort,
+//! Shell sort, quicksort, heapsort and introsort are not, since they move
+//! elements across long distances, past their equals.
+//!
+//! To choose by that trade-off rather than by algorithm, [`sort_stable`] uses
+//! merge sort, which keeps equal elements in order but clones every element
+//! into an O(n) scratch buffer. [`sort_unstable`] uses introsort, which sorts
+//! in place with no clones and O(log n) auxiliary space, and is usually
+//! faster, but may reorder equal elements. The two only differ when equal
+//! elements are distinguishable, e.g. records compared by one field.
+//!
+//! Every sort orders ascending, the `_desc` variants order descending. Where a
+//! sort has a `_by` variant, its `_desc` variant flips the comparison, so equal
+//! elements keep their original relative order and a stable sort stays
+//! stable. Reversing an ascending sort would instead reverse the order of
+//! equal elements.
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use crate::heap::BinaryHeap;
+use crate::search::partition_point;
+
+pub mod external;
+
+/// The length at or below which [`par_merge_sort`] sorts sequentially.
+pub const PAR_MERGE_SORT_CUTOFF: usize = 2048;
+
+/// The length below which [`intro_sort`] sorts a partition using insertion
+/// sort.
+pub const INTRO_SORT_INSERTION_CUTOFF: usize = 16;
+
+/// The work done by a sort, as tallied by the `_counted` variants.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SortStats {
+    /// Number of comparisons between two elements.
+    pub comparisons: usize,
+    /// Number of swaps of two elements.
+    pub swaps: usize,
+}
 
 /// Sorts in place using bubble sort.
 ///
@@ -9,25 +53,159 @@
 /// is sorted. In practice this means there's a constant bubbling of the biggest
 /// element from an unsorted lhs section to the top rhs section.
 ///
+/// Each pass through stops at the last swap of the previous one, since the
+/// elements after it are already in their final position.
+///
 /// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
 /// Best-case performance: O(n) comparisons, O(1) swaps.
 /// Worst-case space complexity: O(n) total, O(1) auxiliary.
 pub fn bubble_sort<T: PartialOrd>(list: &mut [T]) {
-    // for _ in 0..list.len() {
+    bubble_sort_counted(list);
+}
+
+/// Sorts in place using bubble sort, returning the number of comparisons and
+/// swaps performed.
+///
+/// See [`bubble_sort`].
+pub fn bubble_sort_counted<T: PartialOrd>(list: &mut [T]) -> SortStats {
+    let mut stats = SortStats::default();
+
+    // Nothing to compare, and `n - 1` below would underflow on an empty list.
+    if list.len() < 2 {
+        return stats;
+    }
+
+    // The length of the unsorted lhs section.
+    let mut n = list.len();
     loop {
-        let mut swapped = false;
-        for i in 0..list.len() - 1 {
+        let mut last_swap = 0;
+        for i in 0..n - 1 {
+            stats.comparisons += 1;
+            if list[i] > list[i + 1] {
+                list.swap(i, i + 1);
+                stats.swaps += 1;
+                last_swap = i + 1;
+            }
+        }
+        // Everything from the last swap onwards has bubbled to its final
+        // position, so the next pass through can stop short of it.
+        n = last_swap;
+        // If no swaps occurred, or at most one unsorted element remains, the list
+        // is sorted and no further pass through is necessary.
+        if n < 2 {
+            break;
+        }
+    }
+    stats
+}
+
+/// Sorts in place using cocktail shaker sort.
+///
+/// A bidirectional bubble sort. Each pass through first bubbles the biggest
+/// element rightwards, as in [`bubble_sort`], then steps back through the
+/// list bubbling the smallest element leftwards. In practice this means
+/// there's a sorted section growing on both the lhs and the rhs, around a
+/// shrinking unsorted middle section.
+///
+/// Bubble sort moves a small element near the end of the list, a "turtle",
+/// only one position leftwards per pass through, so a single turtle costs
+/// O(n) passes. Here it reaches its final position in a single backwards
+/// pass.
+///
+/// Each pass stops at the last swap of the previous one in its direction,
+/// since the elements beyond it are already in their final position.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
+/// Best-case performance: O(n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn cocktail_sort<T: PartialOrd>(list: &mut [T]) {
+    cocktail_sort_counted(list);
+}
+
+/// Sorts in place using cocktail shaker sort, returning the number of
+/// comparisons and swaps performed.
+///
+/// See [`cocktail_sort`].
+pub fn cocktail_sort_counted<T: PartialOrd>(list: &mut [T]) -> SortStats {
+    let mut stats = SortStats::default();
+
+    // The unsorted middle section, `lo..hi`.
+    let (mut lo, mut hi) = (0, list.len());
+    // If at most one unsorted element remains, the list is sorted.
+    while hi - lo > 1 {
+        // Bubble the biggest element rightwards.
+        let mut last_swap = lo;
+        for i in lo..hi - 1 {
+            stats.comparisons += 1;
             if list[i] > list[i + 1] {
                 list.swap(i, i + 1);
+                stats.swaps += 1;
+                last_swap = i + 1;
+            }
+        }
+        // If no swaps occurred, this collapses the section and ends the sort.
+        hi = last_swap;
+
+        // Bubble the smallest element leftwards.
+        let mut first_swap = hi;
+        for i in (lo + 1..hi).rev() {
+            stats.comparisons += 1;
+            if list[i - 1] > list[i] {
+                list.swap(i - 1, i);
+                stats.swaps += 1;
+                first_swap = i;
+            }
+        }
+        lo = first_swap;
+    }
+    stats
+}
+
+/// Sorts in place using comb sort.
+///
+/// A bubble sort that compares elements a gap apart rather than adjacent ones.
+/// The gap starts at the length of the list and shrinks by a factor of 1.3
+/// each pass through, so turtles, small elements near the end of the list, move
+/// leftwards a long way early on rather than one position per pass. Once the
+/// gap reaches 1 it's a plain bubble sort, which stops after a pass through
+/// with no swaps.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
+/// Average performance: O(n^2 / 2^p) comparisons, for p gap shrinks.
+/// Best-case performance: O(n log n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn comb_sort<T: PartialOrd>(list: &mut [T]) {
+    comb_sort_counted(list);
+}
+
+/// Sorts in place using comb sort, returning the number of comparisons and
+/// swaps performed.
+///
+/// See [`comb_sort`].
+pub fn comb_sort_counted<T: PartialOrd>(list: &mut [T]) -> SortStats {
+    let mut stats = SortStats::default();
+
+    let mut gap = list.len();
+    loop {
+        // Shrink by a factor of 1.3, without going below 1.
+        gap = (gap * 10 / 13).max(1);
+
+        let mut swapped = false;
+        for i in 0..list.len().saturating_sub(gap) {
+            stats.comparisons += 1;
+            if list[i] > list[i + gap] {
+                list.swap(i, i + gap);
+                stats.swaps += 1;
                 swapped = true;
             }
         }
-        // If no swaps occurred, the list is sorted and no further pass through is
-        // necessary.
-        if !swapped {
+
+        // Only a pass through with a gap of 1 checks every adjacent pair.
+        if gap == 1 && !swapped {
             break;
         }
     }
+    stats
 }
 
 /// Sorts in place using insertion sort.
@@ -42,8 +220,8 @@ pub fn bubble_sort<T: PartialOrd>(list: &mut [T]) {
 /// smaller, shift all the larger elements and insert into that correct
 /// position.
 ///
-/// Insertion sort requires less comparisons and less swaps than bubble sort and
-/// so is more efficient for large inputs.
+/// Insertion sort requires less comparisons than bubble sort and so is more
+/// efficient for large inputs. Both perform one swap per out of order pair.
 ///
 /// E.g.
 ///
@@ -59,8 +237,9 @@ pub fn bubble_sort<T: PartialOrd>(list: &mut [T]) {
 /// 1 2 5 3 6
 /// 1 2 3 5 6
 /// 1 2 3 5 6
+/// 1 2 3 5 6
 ///
-/// Comparison count    8
+/// Comparison count    9
 /// Swap count          4
 ///
 /// INSERTION
@@ -74,31 +253,433 @@ pub fn bubble_sort<T: PartialOrd>(list: &mut [T]) {
 /// 1 2 3 5 6
 ///
 /// Comparison count    7
-/// Swap count          3
+/// Swap count          4
 ///
 /// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
 /// Best-case performance: O(n) comparisons, O(1) swaps.
 /// Worst-case space complexity: O(n) total, O(1) auxiliary.
 pub fn insertion_sort<T: PartialOrd>(list: &mut [T]) {
+    insertion_sort_by(list, ascending);
+}
+
+/// Sorts in place using insertion sort, returning the number of comparisons
+/// and swaps performed.
+///
+/// See [`insertion_sort`].
+pub fn insertion_sort_counted<T: PartialOrd>(list: &mut [T]) -> SortStats {
+    insertion_sort_by_counted(list, ascending)
+}
+
+/// Sorts in place using insertion sort, ordering elements with `cmp`.
+///
+/// See [`insertion_sort`].
+pub fn insertion_sort_by<T, F>(list: &mut [T], cmp: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    insertion_sort_by_counted(list, cmp);
+}
+
+/// Sorts in place using insertion sort, in non-increasing order.
+///
+/// See [`insertion_sort`].
+pub fn insertion_sort_desc<T: PartialOrd>(list: &mut [T]) {
+    insertion_sort_by(list, descending);
+}
+
+/// Shared implementation of [`insertion_sort_by`] and
+/// [`insertion_sort_counted`].
+fn insertion_sort_by_counted<T, F>(list: &mut [T], cmp: F) -> SortStats
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut stats = SortStats::default();
     // Iterate for each element `i`, starting from index 1 since insertion sort
     // compares `i` to `i - 1`.
     for i in 1..list.len() {
         let mut j = i;
-        // while j > 0 {
-        //     if list[j - 1] > list[j] {
-        //         ...
-        //     } else {
-        //         break;
-        //     }
-        while j > 0 && list[j - 1] > list[j] {
+        while j > 0 {
+            stats.comparisons += 1;
+            if cmp(&list[j - 1], &list[j]) != Ordering::Greater {
+                break;
+            }
             list.swap(j - 1, j);
+            stats.swaps += 1;
             j -= 1;
         }
     }
+    stats
+}
+
+/// Sorts in place using binary insertion sort.
+///
+/// As in [`insertion_sort`], grow a sorted section on the lhs one element at a
+/// time, but rather than comparing the next element against each sorted
+/// element in turn, binary search the sorted section for where it belongs.
+/// Then shift the sorted elements after that point right by one to make room
+/// for it.
+///
+/// The search finds the position after any equal elements, so the sort is
+/// stable. It cuts the comparisons to O(log i) for the `i`th element, but the
+/// shifting still moves up to `i` elements, so this only beats insertion sort
+/// when comparisons are expensive relative to moves.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n^2) swaps.
+/// Best-case performance: O(n log n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn binary_insertion_sort<T: Ord + Clone>(list: &mut [T]) {
+    binary_insertion_sort_counted(list);
+}
+
+/// Sorts in place using binary insertion sort, returning the number of
+/// comparisons and swaps performed, where shifting an element by one counts as
+/// a swap.
+///
+/// See [`binary_insertion_sort`].
+pub fn binary_insertion_sort_counted<T: Ord + Clone>(list: &mut [T]) -> SortStats {
+    let comparisons = Cell::new(0);
+    let mut swaps = 0;
+    for i in 1..list.len() {
+        let item = list[i].clone();
+        let position = partition_point(&list[..i], |x| {
+            comparisons.set(comparisons.get() + 1);
+            *x <= item
+        });
+        list[position..=i].rotate_right(1);
+        swaps += i - position;
+    }
+    SortStats { comparisons: comparisons.get(), swaps }
+}
+
+/// Sorts in place using gnome sort.
+///
+/// Walk the list with a single index. If the element at the index is in order
+/// with the one before it, step forwards, otherwise swap them and step
+/// backwards. The walk ends when it steps off the end of the list.
+///
+/// This is insertion sort with swaps, where stepping backwards sinks an element
+/// into the sorted lhs section, but with no separate inner loop, so after
+/// sinking an element the walk steps forwards back over the sorted section.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
+/// Best-case performance: O(n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn gnome_sort<T: PartialOrd>(list: &mut [T]) {
+    let mut i = 1;
+    while i < list.len() {
+        if i == 0 || list[i - 1] <= list[i] {
+            i += 1;
+        } else {
+            list.swap(i - 1, i);
+            i -= 1;
+        }
+    }
+}
+
+/// Sorts in place using selection sort.
+///
+/// Iterate the list, and for each index, scan the unsorted rhs for the
+/// smallest element and swap it into that index. In practice this means
+/// there's a constant selecting of the smallest element from an unsorted
+/// section on the rhs onto the end of a sorted section on the lhs.
+///
+/// Selection sort always performs n(n - 1) / 2 comparisons, even on sorted
+/// input, but at most n - 1 swaps, one per index. Bubble sort can swap O(n^2)
+/// times, so selection sort is the better choice when swaps are expensive.
+///
+/// E.g.
+///
+/// 5 1 2 6 3
+///
+/// SELECTION
+///
+/// 1 5 2 6 3
+/// 1 2 5 6 3
+/// 1 2 3 6 5
+/// 1 2 3 5 6
+///
+/// Comparison count    10
+/// Swap count          4
+///
+/// Worst-case performance: O(n^2) comparisons, O(n) swaps.
+/// Best-case performance: O(n^2) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn selection_sort<T: PartialOrd>(list: &mut [T]) {
+    // The last element is in place once every other element is.
+    for i in 0..list.len().saturating_sub(1) {
+        let mut min = i;
+        for j in i + 1..list.len() {
+            if list[j] < list[min] {
+                min = j;
+            }
+        }
+        if min != i {
+            list.swap(i, min);
+        }
+    }
+}
+
+/// Sorts in place using cycle sort.
+///
+/// Iterate the list, and for each index, take its element and count the
+/// elements on the rhs smaller than it. That count is how far past the index
+/// the element belongs, so write it there, past any equal elements already
+/// placed, and take the element it displaces. Repeat with the
+/// displaced element until the cycle leads back to the starting index. In
+/// practice this means every element is written straight into its final
+/// position.
+///
+/// Elements already in their final position are never written, and every
+/// other element is written exactly once, which is the minimum possible. This
+/// suits memory where writes are expensive, e.g. flash memory, at the cost of
+/// always performing O(n^2) comparisons.
+///
+/// E.g.
+///
+/// 5 1 2 6 3
+///
+/// CYCLE
+///
+/// 5 1 2 5 3    write 5 at 3, take 6
+/// 5 1 2 5 6    write 6 at 4, take 3
+/// 5 1 3 5 6    write 3 at 2, take 2
+/// 5 2 3 5 6    write 2 at 1, take 1
+/// 1 2 3 5 6    write 1 at 0, back at the start
+///
+/// Write count         5
+///
+/// Worst-case performance: O(n^2) comparisons, O(n) writes.
+/// Best-case performance: O(n^2) comparisons, O(1) writes.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn cycle_sort<T: PartialOrd + Clone>(list: &mut [T]) {
+    cycle_sort_counted(list);
+}
+
+/// Sorts in place using cycle sort, returning the number of comparisons and
+/// writes performed.
+///
+/// Each write swaps the held element with the one in the list, so writes are
+/// counted as swaps.
+///
+/// See [`cycle_sort`].
+pub fn cycle_sort_counted<T: PartialOrd + Clone>(list: &mut [T]) -> SortStats {
+    let mut stats = SortStats::default();
+    for start in 0..list.len().saturating_sub(1) {
+        let mut item = list[start].clone();
+        let mut pos = cycle_position(list, start, &item, &mut stats);
+        if pos == start {
+            // Already in its final position.
+            continue;
+        }
+
+        // Follow the cycle until it leads back to `start`, whose stale copy of
+        // the original element is then overwritten.
+        loop {
+            // Place `item` after any equal elements already in position.
+            while {
+                stats.comparisons += 1;
+                item == list[pos]
+            } {
+                pos += 1;
+            }
+
+            std::mem::swap(&mut item, &mut list[pos]);
+            stats.swaps += 1;
+            if pos == start {
+                break;
+            }
+
+            pos = cycle_position(list, start, &item, &mut stats);
+        }
+    }
+    stats
+}
+
+/// Returns where `item` belongs, i.e. `start` plus the number of elements
+/// after `start` smaller than it.
+fn cycle_position<T: PartialOrd>(
+    list: &[T],
+    start: usize,
+    item: &T,
+    stats: &mut SortStats,
+) -> usize {
+    stats.comparisons += list.len() - start - 1;
+    start + list[start + 1..].iter().filter(|&other| other < item).count()
+}
+
+/// Sorts in place using pancake sort.
+///
+/// The only operation allowed on the list is a flip, reversing a prefix of it,
+/// as with a spatula under a stack of pancakes. Find the biggest element in the
+/// unsorted lhs section, flip it to the front of the list, then flip the whole
+/// unsorted section so it lands at the end, on top of the sorted rhs section.
+/// Repeat until one element remains unsorted.
+///
+/// Each element takes at most two flips, so at most 2(n - 1) flips in total,
+/// though each flip moves O(n) elements.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n) flips.
+/// Best-case performance: O(n^2) comparisons, O(1) flips.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn pancake_sort<T: PartialOrd>(list: &mut [T]) {
+    pancake_sort_flips(list);
+}
+
+/// Sorts in place using pancake sort, returning the size of each flip
+/// performed, in order.
+///
+/// A flip of size k reverses `list[..k]`, so replaying the flips on the
+/// original list with `list[..k].reverse()` sorts it. Flips of size 1 reverse
+/// nothing and are never performed.
+///
+/// See [`pancake_sort`].
+pub fn pancake_sort_flips<T: PartialOrd>(list: &mut [T]) -> Vec<usize> {
+    let mut flips = Vec::new();
+    // The length of the unsorted lhs section.
+    for n in (2..=list.len()).rev() {
+        let mut max = 0;
+        for i in 1..n {
+            if list[i] > list[max] {
+                max = i;
+            }
+        }
+        if max == n - 1 {
+            continue;
+        }
+
+        // Bring the biggest element to the front, then down onto the rhs.
+        if max > 0 {
+            list[..=max].reverse();
+            flips.push(max + 1);
+        }
+        list[..n].reverse();
+        flips.push(n);
+    }
+    flips
+}
+
+/// Sorts in place using Shell sort, with the Knuth gap sequence.
+///
+/// The gaps are 1, 4, 13, 40, ..., i.e. `h = 3h + 1`, starting from the largest
+/// gap below n / 3.
+///
+/// See [`shell_sort_with_gaps`].
+pub fn shell_sort<T: PartialOrd>(list: &mut [T]) {
+    let mut gap = 1;
+    while gap < list.len() / 3 {
+        gap = 3 * gap + 1;
+    }
+
+    while gap > 0 {
+        gapped_insertion_sort(list, gap);
+        gap /= 3;
+    }
+}
+
+/// Sorts in place using Shell sort, with the given gap sequence.
+///
+/// Generalise insertion sort by comparing elements `gap` apart rather than
+/// adjacent elements. For each gap in turn, insertion sort the interleaved
+/// sublists of every gap-th element. Large gaps move elements long distances
+/// in few swaps, so by the time the final gap of 1, which is a plain insertion
+/// sort, is reached, the list is nearly sorted and little work remains.
+///
+/// Panics if `gaps` is not strictly decreasing or doesn't end with 1.
+///
+/// Worst-case performance: depends on the gap sequence, O(n^(3/2)) for Knuth.
+/// Best-case performance: O(n log n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn shell_sort_with_gaps<T: PartialOrd>(list: &mut [T], gaps: &[usize]) {
+    assert_eq!(Some(&1), gaps.last(), "shell sort gaps must end with 1");
+    assert!(gaps.windows(2).all(|w| w[0] > w[1]), "shell sort gaps must be strictly decreasing");
+
+    for &gap in gaps {
+        gapped_insertion_sort(list, gap);
+    }
+}
+
+/// Insertion sorts each sublist of elements `gap` apart.
+fn gapped_insertion_sort<T: PartialOrd>(list: &mut [T], gap: usize) {
+    for i in gap..list.len() {
+        let mut j = i;
+        while j >= gap && list[j - gap] > list[j] {
+            list.swap(j - gap, j);
+            j -= gap;
+        }
+    }
+}
+
+/// Sorts in place using merge sort.
+///
+/// Split the list into a lhs and rhs half, recursively sort each, then merge
+/// the two sorted halves into one. Merging repeatedly takes the smaller of
+/// the two halves' next elements, preferring the lhs on ties, into a scratch
+/// buffer, then copies the buffer back over the list.
+///
+/// One scratch buffer of length n is allocated up front and reused by every
+/// merge. To reuse one across many sorts, see [`merge_sort_with_buffer`].
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Best-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Worst-case space complexity: O(n) total, O(n) auxiliary.
+pub fn merge_sort<T: Clone + PartialOrd>(items: &mut [T]) {
+    merge_sort_with_buffer(items, &mut Vec::with_capacity(items.len()));
+}
+
+/// Sorts in place using merge sort, merging through `scratch` rather than
+/// allocating a buffer.
+///
+/// `scratch` is cleared by each merge, and grows to the length of `list` if it
+/// isn't already that long, so passing the same buffer to repeated sorts saves
+/// reallocating it.
+///
+/// See [`merge_sort`].
+pub fn merge_sort_with_buffer<T: Clone + PartialOrd>(list: &mut [T], scratch: &mut Vec<T>) {
+    merge_sort_by_ref(list, &ascending, scratch);
+}
+
+/// Sorts in place using merge sort, ordering elements with `cmp`.
+///
+/// See [`merge_sort`].
+pub fn merge_sort_by<T, F>(items: &mut [T], cmp: F)
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    merge_sort_by_ref(items, &cmp, &mut Vec::with_capacity(items.len()));
+}
+
+/// Sorts in place using merge sort, ordering elements by the key `key`
+/// extracts from them.
+///
+/// `key` is called on both elements of every comparison, i.e. O(n log n)
+/// times, rather than once per element. For expensive keys, consider caching
+/// them first e.g. by sorting `(key, element)` pairs.
+///
+/// See [`merge_sort`].
+pub fn merge_sort_by_key<T, K, F>(items: &mut [T], key: F)
+where
+    T: Clone,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    merge_sort_by(items, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sorts in place using merge sort, in non-increasing order.
+///
+/// See [`merge_sort`].
+pub fn merge_sort_desc<T: Clone + PartialOrd>(list: &mut [T]) {
+    merge_sort_by(list, descending);
 }
 
-/// TODO: Document algorithm.
-pub fn merge_sort<T: Copy + PartialOrd>(items: &mut [T]) {
+/// Recursive step of [`merge_sort_by`], borrowing `cmp` so each level doesn't
+/// instantiate a new closure type, and merging through `scratch`.
+fn merge_sort_by_ref<T, F>(items: &mut [T], cmp: &F, scratch: &mut Vec<T>)
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
     let len = items.len();
 
     // Base case.
@@ -106,110 +687,2714 @@ pub fn merge_sort<T: Copy + PartialOrd>(items: &mut [T]) {
         return;
     }
 
-    let mut result = Vec::with_capacity(len);
-
     let (left, right) = items.split_at_mut(len / 2);
+    merge_sort_by_ref(left, cmp, scratch);
+    merge_sort_by_ref(right, cmp, scratch);
+
+    merge_halves(items, len / 2, cmp, scratch);
+}
+
+/// Sorts in place using merge sort, sorting the two halves on separate threads.
+///
+/// Recursively split the list in two, as for [`merge_sort`], but sort each
+/// half on its own scoped thread, down to [`PAR_MERGE_SORT_CUTOFF`] elements.
+/// Below that, spawning a thread costs more than it saves, so sort
+/// sequentially with [`merge_sort`]. The merge of the two sorted halves is
+/// sequential.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Best-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Worst-case space complexity: O(n) total, O(n) auxiliary.
+pub fn par_merge_sort<T: Send + Clone + PartialOrd>(list: &mut [T]) {
+    let len = list.len();
+    if len <= PAR_MERGE_SORT_CUTOFF {
+        merge_sort(list);
+        return;
+    }
+
+    let (left, right) = list.split_at_mut(len / 2);
+    std::thread::scope(|scope| {
+        scope.spawn(|| par_merge_sort(left));
+        // Sort the rhs on this thread rather than idling until the lhs is done.
+        par_merge_sort(right);
+    });
+
+    merge_halves(list, len / 2, &ascending, &mut Vec::with_capacity(len));
+}
+
+/// Merges the sorted runs `items[..mid]` and `items[mid..]` into one sorted
+/// run, ordering elements with `cmp`, by way of `scratch`.
+fn merge_halves<T, F>(items: &mut [T], mid: usize, cmp: &F, scratch: &mut Vec<T>)
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let len = items.len();
+    let result = scratch;
+    result.clear();
 
-    merge_sort(left);
-    merge_sort(right);
+    let (left, right) = items.split_at(mid);
 
     let mut l_iter = left.iter().peekable();
     let mut r_iter = right.iter().peekable();
 
     // It is safe to unwrap `next` when `peek` returns `Some`.
     while let (Some(l), Some(r)) = (l_iter.peek(), r_iter.peek()) {
-        if l < r {
-            result.push(*l_iter.next().unwrap());
+        // Prefer the lhs run on ties, which keeps the sort stable.
+        if cmp(l, r) != Ordering::Greater {
+            result.push(l_iter.next().unwrap().clone());
         } else {
-            result.push(*r_iter.next().unwrap());
+            result.push(r_iter.next().unwrap().clone());
         }
     }
 
     // Copy leftovers e.g. for `items` with value `[2, 3, 1]`, `left` is `[2, 3]`
     // and right is `[1]`, so `[3]` has not been accounted for in `result`.
     for l in l_iter {
-        result.push(*l);
+        result.push(l.clone());
     }
     for r in r_iter {
-        result.push(*r);
+        result.push(r.clone());
     }
 
     assert_eq!(len, result.len());
 
     // Copy the result to the original `items`.
-    items[..len].copy_from_slice(&result[..len]);
+    items[..len].clone_from_slice(&result[..len]);
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+/// Sorts in place using bottom-up merge sort.
+///
+/// Rather than recursively splitting the list, treat it as runs of width 1,
+/// which are trivially sorted, and merge adjacent pairs of runs into runs of
+/// width 2, then 4, 8 and so on until a single run covers the whole list.
+///
+/// Each pass merges from one buffer into the other, ping-ponging between
+/// `list` and a single scratch buffer that is allocated once up front. The
+/// recursive [`merge_sort`] instead merges into its scratch buffer and copies
+/// every merge back.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Best-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Worst-case space complexity: O(n) total, O(n) auxiliary.
+pub fn merge_sort_iterative<T: Clone + PartialOrd>(list: &mut [T]) {
+    let len = list.len();
+    if len < 2 {
+        return;
+    }
 
-    #[test]
-    fn test_bubble_sort() {
-        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
-        bubble_sort(list1);
-        assert_eq!(&mut [1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list1);
+    let mut scratch = list.to_vec();
+    // Whether the runs of the current pass live in `list` or `scratch`.
+    let mut in_list = true;
 
-        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
-        bubble_sort(list2);
-        assert_eq!(&mut [-1, 1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list2);
+    let mut width = 1;
+    while width < len {
+        if in_list {
+            merge_runs(list, &mut scratch, width);
+        } else {
+            merge_runs(&scratch, list, width);
+        }
+        in_list = !in_list;
+        width *= 2;
+    }
 
-        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
-        bubble_sort(list3);
-        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+    // An odd number of passes leaves the result in `scratch`.
+    if !in_list {
+        list.clone_from_slice(&scratch);
+    }
+}
 
-        let list4 = &mut ['a', 'c', 'b'];
-        bubble_sort(list4);
-        assert_eq!(&mut ['a', 'b', 'c'], list4);
+/// Merges each pair of adjacent `width` sized runs in `src` into `dst`.
+fn merge_runs<T: Clone + PartialOrd>(src: &[T], dst: &mut [T], width: usize) {
+    let len = src.len();
+    let mut start = 0;
+    while start < len {
+        // The last pair may be short, or only have a lhs run.
+        let mid = (start + width).min(len);
+        let end = (start + 2 * width).min(len);
 
-        let list5 = &mut ["Test", "A old day", "A new day"];
-        bubble_sort(list5);
-        assert_eq!(&mut ["A new day", "A old day", "Test"], list5);
+        let (left, right) = (&src[start..mid], &src[mid..end]);
+        let (mut i, mut j) = (0, 0);
+        for slot in &mut dst[start..end] {
+            // Prefer the lhs run on ties, which keeps the sort stable.
+            if j == right.len() || (i < left.len() && left[i] <= right[j]) {
+                slot.clone_from(&left[i]);
+                i += 1;
+            } else {
+                slot.clone_from(&right[j]);
+                j += 1;
+            }
+        }
+
+        start = end;
     }
+}
 
-    #[test]
-    fn test_insertion_sort() {
-        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
-        insertion_sort(list1);
-        assert_eq!(&mut [1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list1);
+/// Sorts in place using natural merge sort.
+///
+/// Like [`merge_sort_iterative`], but rather than starting from runs of width
+/// 1, start from the runs already in the list, i.e. its maximal non-decreasing
+/// stretches, found in a single pass. Then merge adjacent pairs of runs
+/// bottom-up until a single run covers the whole list.
+///
+/// A list made of r runs takes log r passes of merging, so partially sorted
+/// input does far less work, and sorted input is a single run that needs no
+/// merging at all.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) copies.
+/// Best-case performance: O(n) comparisons, O(1) copies.
+/// Worst-case space complexity: O(n) total, O(n) auxiliary.
+pub fn natural_merge_sort<T: Clone + PartialOrd>(list: &mut [T]) {
+    natural_merge_sort_counted(list);
+}
 
-        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
-        insertion_sort(list2);
-        assert_eq!(&mut [-1, 1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list2);
+/// Sorts in place using natural merge sort, returning the number of
+/// comparisons performed.
+///
+/// Merging copies elements rather than swapping them, so no swaps are counted.
+///
+/// See [`natural_merge_sort`].
+pub fn natural_merge_sort_counted<T: Clone + PartialOrd>(list: &mut [T]) -> SortStats {
+    let comparisons = Cell::new(0);
+    let cmp = |a: &T, b: &T| {
+        comparisons.set(comparisons.get() + 1);
+        ascending(a, b)
+    };
 
-        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
-        insertion_sort(list3);
+    // The start of each run, followed by the end of the list.
+    let mut bounds = vec![0];
+    for i in 1..list.len() {
+        if cmp(&list[i - 1], &list[i]) == Ordering::Greater {
+            bounds.push(i);
+        }
+    }
+    bounds.push(list.len());
+
+    let mut scratch = Vec::with_capacity(list.len());
+    while bounds.len() > 2 {
+        let mut merged = vec![0];
+        for pair in bounds[1..].chunks(2) {
+            // An odd run out at the end is carried over to the next pass as is.
+            if let [mid, end] = *pair {
+                // Safe to unwrap, `merged` starts with the start of the list.
+                let start = *merged.last().unwrap();
+                merge_halves(&mut list[start..end], mid - start, &cmp, &mut scratch);
+                merged.push(end);
+            } else {
+                merged.push(pair[0]);
+            }
+        }
+        bounds = merged;
+    }
+
+    SortStats { comparisons: comparisons.get(), swaps: 0 }
+}
+
+/// Merges the sorted `runs` into one sorted `Vec`, using a min-heap.
+///
+/// Keep the next unmerged element of each run on a min-heap, alongside the
+/// run's index and the element's position in it. Repeatedly pop the smallest,
+/// append it to the result and push the next element of the same run. The
+/// merge step of [`merge_sort`] is the case of k = 2, and
+/// [`external_merge_sort`](external::external_merge_sort) merges its runs from
+/// disk the same way.
+///
+/// Equal elements are taken from earlier runs first, and keep their order
+/// within a run, so the merge is stable.
+///
+/// Worst-case performance: O(n log k), for n elements over k runs.
+/// Worst-case space complexity: O(n) total, O(k) auxiliary.
+pub fn k_way_merge<T: Ord + Clone>(runs: &[Vec<T>]) -> Vec<T> {
+    let mut merged = Vec::with_capacity(runs.iter().map(Vec::len).sum());
+
+    // Ties on the element fall back to the run's index, keeping the merge stable.
+    let mut heap = BinaryHeap::new_min();
+    for (run, items) in runs.iter().enumerate() {
+        if let Some(first) = items.first() {
+            heap.push((first.clone(), run, 0));
+        }
+    }
+
+    while let Some((item, run, i)) = heap.pop() {
+        merged.push(item);
+        if let Some(next) = runs[run].get(i + 1) {
+            heap.push((next.clone(), run, i + 1));
+        }
+    }
+    merged
+}
+
+/// Sorts in place using quicksort.
+///
+/// Pick a pivot element and partition the list around it, so that every
+/// element smaller than the pivot ends up on the lhs and every other element
+/// ends up on the rhs. The pivot is then in its final position, and the same
+/// is repeated on the lhs and rhs partitions until they are empty.
+///
+/// The pivot is the median of the first, middle and last elements. Always
+/// picking e.g. the last element degrades to O(n^2) on already sorted input,
+/// since each partition then only peels off a single element.
+///
+/// Only the smaller partition is recursed into, the larger one is sorted by
+/// the next iteration of the loop. This bounds the stack depth to O(log n)
+/// even when the partitions are badly unbalanced.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
+/// Average performance: O(n log n) comparisons, O(n log n) swaps.
+/// Worst-case space complexity: O(n) total, O(log n) auxiliary.
+pub fn quick_sort<T: PartialOrd>(list: &mut [T]) {
+    quick_sort_by(list, ascending);
+}
+
+/// Sorts in place using quicksort, ordering elements with `cmp`.
+///
+/// See [`quick_sort`].
+pub fn quick_sort_by<T, F>(list: &mut [T], cmp: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    quick_sort_by_ref(list, &cmp);
+}
+
+/// Sorts in place using quicksort, in non-increasing order.
+///
+/// See [`quick_sort`].
+pub fn quick_sort_desc<T: PartialOrd>(list: &mut [T]) {
+    quick_sort_by(list, descending);
+}
+
+/// Recursive step of [`quick_sort_by`], borrowing `cmp` so each level doesn't
+/// instantiate a new closure type.
+fn quick_sort_by_ref<T, F>(mut list: &mut [T], cmp: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    while list.len() > 1 {
+        let pivot = median_of_three_by(list, cmp);
+        let pivot = partition_by(list, pivot, cmp);
+
+        // Take `list` out so the partitions can outlive this iteration.
+        let (left, right) = std::mem::take(&mut list).split_at_mut(pivot);
+        // The pivot is in its final position, exclude it from the rhs.
+        let right = &mut right[1..];
+
+        if left.len() < right.len() {
+            quick_sort_by_ref(left, cmp);
+            list = right;
+        } else {
+            quick_sort_by_ref(right, cmp);
+            list = left;
+        }
+    }
+}
+
+/// Sorts in place using three-way quicksort.
+///
+/// Like [`quick_sort`], but partition the list into three regions rather than
+/// two, following Dijkstra's Dutch national flag scheme: elements smaller
+/// than the pivot on the lhs, elements equal to it in the middle, and larger
+/// elements on the rhs. Scan the unclassified elements between the middle and
+/// rhs regions, growing whichever region each one belongs to. The middle
+/// region is then in its final position, so only the lhs and rhs regions need
+/// sorting.
+///
+/// A plain quicksort puts the elements equal to the pivot in one partition,
+/// so a list of many equal elements only peels off a single element per
+/// partition and degrades to O(n^2). Here every element equal to the pivot is
+/// placed in one pass, so a list of k distinct values takes O(nk) at worst.
+///
+/// Worst-case performance: O(n^2) comparisons, O(n^2) swaps.
+/// Average performance: O(n log n) comparisons, O(n log n) swaps.
+/// Best-case performance: O(n) comparisons, O(1) swaps.
+/// Worst-case space complexity: O(n) total, O(log n) auxiliary.
+pub fn quick_sort_three_way<T: PartialOrd + Clone>(list: &mut [T]) {
+    quick_sort_three_way_counted(list);
+}
+
+/// Sorts in place using three-way quicksort, returning the number of
+/// comparisons and swaps performed.
+///
+/// See [`quick_sort_three_way`].
+pub fn quick_sort_three_way_counted<T: PartialOrd + Clone>(list: &mut [T]) -> SortStats {
+    let comparisons = Cell::new(0);
+    let cmp = |a: &T, b: &T| {
+        comparisons.set(comparisons.get() + 1);
+        ascending(a, b)
+    };
+    let swaps = quick_sort_three_way_by_ref(list, &cmp);
+    SortStats { comparisons: comparisons.get(), swaps }
+}
+
+/// Recursive step of [`quick_sort_three_way_counted`], returning the number of
+/// swaps performed.
+fn quick_sort_three_way_by_ref<T, F>(mut list: &mut [T], cmp: &F) -> usize
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut swaps = 0;
+    while list.len() > 1 {
+        let pivot = list[median_of_three_by(list, cmp)].clone();
+
+        // Everything before `lt` is smaller than the pivot, everything from `gt`
+        // on is larger, and everything in `lt..i` is equal to it.
+        let (mut lt, mut i, mut gt) = (0, 0, list.len());
+        while i < gt {
+            match cmp(&list[i], &pivot) {
+                Ordering::Less => {
+                    list.swap(lt, i);
+                    swaps += 1;
+                    lt += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    gt -= 1;
+                    list.swap(i, gt);
+                    swaps += 1;
+                }
+                Ordering::Equal => i += 1,
+            }
+        }
+
+        // Take `list` out so the regions can outlive this iteration.
+        let (left, rest) = std::mem::take(&mut list).split_at_mut(lt);
+        // The middle region is in its final position, exclude it from the rhs.
+        let right = &mut rest[gt - lt..];
+
+        if left.len() < right.len() {
+            swaps += quick_sort_three_way_by_ref(left, cmp);
+            list = right;
+        } else {
+            swaps += quick_sort_three_way_by_ref(right, cmp);
+            list = left;
+        }
+    }
+    swaps
+}
+
+/// Returns the index of the median of the first, middle and last elements.
+fn median_of_three_by<T, F>(list: &[T], cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let (first, mid, last) = (0, list.len() / 2, list.len() - 1);
+    let less = |a: usize, b: usize| cmp(&list[a], &list[b]) == Ordering::Less;
+
+    if less(first, mid) {
+        if less(mid, last) {
+            mid
+        } else if less(first, last) {
+            last
+        } else {
+            first
+        }
+    } else if less(first, last) {
+        first
+    } else if less(mid, last) {
+        last
+    } else {
+        mid
+    }
+}
+
+/// Partitions in place around the element at `pivot_index`, returning the
+/// pivot's final index, the partition step of [`quick_sort`].
+///
+/// Afterwards, for the returned index `p`, `list[p]` is the pivot, every
+/// element of `list[..p]` is less than it, and every element of `list[p + 1..]`
+/// is greater than or equal to it. So the pivot is in its final sorted
+/// position, and `p` is the number of elements less than it.
+///
+/// Uses the Lomuto scheme. Move the pivot to the end, then scan the rest of the
+/// list, swapping each element less than the pivot into a growing lhs
+/// section. Finally swap the pivot in after that section.
+///
+/// Panics if `pivot_index` is out of bounds.
+///
+/// Worst-case performance: O(n) comparisons, O(n) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn partition<T: PartialOrd>(list: &mut [T], pivot_index: usize) -> usize {
+    assert!(pivot_index < list.len(), "index {pivot_index} is out of bounds");
+    partition_by(list, pivot_index, &ascending)
+}
+
+/// Partitions around the element at `pivot_index` using the Lomuto scheme and
+/// returns the pivot's final index.
+fn partition_by<T, F>(list: &mut [T], pivot_index: usize, cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let last = list.len() - 1;
+
+    // Move the pivot out of the way, to the end.
+    list.swap(pivot_index, last);
+
+    // Everything before `store` is smaller than the pivot.
+    let mut store = 0;
+    for i in 0..last {
+        if cmp(&list[i], &list[last]) == Ordering::Less {
+            list.swap(i, store);
+            store += 1;
+        }
+    }
+
+    // Move the pivot in between the two partitions.
+    list.swap(store, last);
+    store
+}
+
+/// Returns the `k`th smallest element, counting from 0, using quickselect.
+///
+/// Partition the list around a median-of-three pivot, as in [`quick_sort`].
+/// The pivot is then in its final sorted position. If that's index `k`, it's
+/// the answer, otherwise only the partition containing `k` needs searching,
+/// so repeat on that partition alone.
+///
+/// `list` is reordered as a side effect, and afterwards `list[k]` holds the
+/// returned element.
+///
+/// Returns `None` if `k` is out of bounds.
+///
+/// Worst-case performance: O(n^2) comparisons.
+/// Average performance: O(n) comparisons.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn quickselect<T: PartialOrd + Clone>(list: &mut [T], k: usize) -> Option<T> {
+    if k >= list.len() {
+        return None;
+    }
+
+    nth_element(list, k);
+    Some(list[k].clone())
+}
+
+/// Partially sorts in place so that `list[n]` holds the element it would in
+/// the fully sorted list, like C++'s `std::nth_element`.
+///
+/// Afterwards every element of `list[..n]` is less than or equal to `list[n]`,
+/// and every element of `list[n + 1..]` is greater than or equal to it, but
+/// each side is otherwise left in no particular order.
+///
+/// Uses the partition loop of [`quickselect`]. Each partition puts its pivot
+/// in its final sorted position, with smaller elements on its lhs and the rest
+/// on its rhs. Repeating on just the side containing `n` only ever moves
+/// elements within that side, so the earlier partitions still hold once the
+/// pivot lands on `n`.
+///
+/// Panics if `n` is out of bounds.
+///
+/// Worst-case performance: O(n^2) comparisons.
+/// Average performance: O(n) comparisons.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn nth_element<T: PartialOrd>(list: &mut [T], n: usize) {
+    assert!(n < list.len(), "index {n} is out of bounds");
+
+    // The `n`th smallest element is always within `lo..hi`.
+    let (mut lo, mut hi) = (0, list.len());
+    loop {
+        let sub = &mut list[lo..hi];
+        let pivot = median_of_three_by(sub, &ascending);
+        let pivot = lo + partition_by(sub, pivot, &ascending);
+
+        match n.cmp(&pivot) {
+            Ordering::Equal => return,
+            Ordering::Less => hi = pivot,
+            Ordering::Greater => lo = pivot + 1,
+        }
+    }
+}
+
+/// Sorts in place using heapsort.
+///
+/// Rearrange the list into a binary max-heap, where the element at index `i`
+/// is no smaller than its children at `2i + 1` and `2i + 2`. The largest
+/// element is then at the root, so swap it to the end of the list, shrink the
+/// heap by one and sift the new root down to restore the heap property.
+/// Repeat until the heap is empty. In practice this means there's a constant
+/// draining of the biggest element from a heap on the lhs into a sorted
+/// section on the rhs.
+///
+/// The heap is built bottom-up (Floyd's method) by sifting down every parent,
+/// starting from the last one, which takes O(n) rather than the O(n log n) of
+/// inserting the elements one at a time.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) swaps.
+/// Best-case performance: O(n log n) comparisons, O(n log n) swaps.
+/// Worst-case space complexity: O(n) total, O(1) auxiliary.
+pub fn heap_sort<T: PartialOrd>(list: &mut [T]) {
+    let len = list.len();
+
+    // Heapify, starting from the last parent, i.e. the parent of `len - 1`.
+    for i in (0..len / 2).rev() {
+        sift_down(list, i, len);
+    }
+
+    // Move the root to the end of the heap and restore the heap property over
+    // the remaining prefix.
+    for end in (1..len).rev() {
+        list.swap(0, end);
+        sift_down(list, 0, end);
+    }
+}
+
+/// Sorts in place using heapsort, in non-increasing order.
+///
+/// Heapsort has no `_by` variant to flip the comparison of, so this sorts
+/// ascending then reverses. That reverses the order of equal elements too,
+/// but heapsort doesn't keep them in their original order anyway.
+///
+/// See [`heap_sort`].
+pub fn heap_sort_desc<T: PartialOrd>(list: &mut [T]) {
+    heap_sort(list);
+    list.reverse();
+}
+
+/// Sifts the element at `root` down the max-heap stored in `list[..end]`.
+fn sift_down<T: PartialOrd>(list: &mut [T], mut root: usize, end: usize) {
+    loop {
+        let left = 2 * root + 1;
+        if left >= end {
+            break;
+        }
+
+        // Pick the larger of the two children.
+        let right = left + 1;
+        let child = if right < end && list[left] < list[right] { right } else { left };
+
+        if list[root] >= list[child] {
+            break;
+        }
+
+        list.swap(root, child);
+        root = child;
+    }
+}
+
+/// Sorts in place using introsort.
+///
+/// A hybrid of quicksort, heapsort and insertion sort. Partition the list as
+/// in [`quick_sort`], but keep track of the depth of partitioning. Once it
+/// exceeds 2 floor(log2(n)) the pivots are evidently poor, so sort the
+/// remaining partition using [`heap_sort`] instead, which bounds the worst
+/// case to O(n log n). Partitions shorter than
+/// [`INTRO_SORT_INSERTION_CUTOFF`] are sorted using [`insertion_sort`], which
+/// is faster than either on so few elements.
+///
+/// Worst-case performance: O(n log n) comparisons, O(n log n) swaps.
+/// Average performance: O(n log n) comparisons, O(n log n) swaps.
+/// Worst-case space complexity: O(n) total, O(log n) auxiliary.
+pub fn intro_sort<T: PartialOrd>(list: &mut [T]) {
+    if list.is_empty() {
+        return;
+    }
+    let depth_limit = 2 * list.len().ilog2() as usize;
+    intro_sort_with_depth(list, depth_limit);
+}
+
+/// Recursive step of [`intro_sort`], switching to heapsort once `depth` more
+/// partitions have been made.
+fn intro_sort_with_depth<T: PartialOrd>(mut list: &mut [T], mut depth: usize) {
+    while list.len() >= INTRO_SORT_INSERTION_CUTOFF {
+        if depth == 0 {
+            heap_sort(list);
+            return;
+        }
+        depth -= 1;
+
+        let pivot = median_of_three_by(list, &ascending);
+        let pivot = partition_by(list, pivot, &ascending);
+
+        // Take `list` out so the partitions can outlive this iteration.
+        let (left, right) = std::mem::take(&mut list).split_at_mut(pivot);
+        // The pivot is in its final position, exclude it from the rhs.
+        let right = &mut right[1..];
+
+        if left.len() < right.len() {
+            intro_sort_with_depth(left, depth);
+            list = right;
+        } else {
+            intro_sort_with_depth(right, depth);
+            list = left;
+        }
+    }
+    insertion_sort(list);
+}
+
+/// Sorts in place using counting sort.
+///
+/// Rather than comparing elements, count how often each value occurs in a
+/// frequency table indexed by `value - min`, then iterate the table in order
+/// and write each value back out as many times as it was counted.
+///
+/// The table is `max - min + 1` entries wide, so counting sort is only a good
+/// fit when that range k is small relative to n. A slice containing both 0 and
+/// `u32::MAX` needs a table of 2^32 entries.
+///
+/// Worst-case performance: O(n + k).
+/// Best-case performance: O(n + k).
+/// Worst-case space complexity: O(n + k) total, O(k) auxiliary.
+pub fn counting_sort(list: &mut [u32]) {
+    let (Some(&min), Some(&max)) = (list.iter().min(), list.iter().max()) else {
+        return;
+    };
+
+    let mut counts = vec![0usize; (max - min) as usize + 1];
+    for &value in list.iter() {
+        counts[(value - min) as usize] += 1;
+    }
+
+    let mut i = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        let value = min + offset as u32;
+        list[i..i + count].fill(value);
+        i += count;
+    }
+}
+
+/// Sorts in place using least significant digit radix sort, with base 256.
+///
+/// See [`radix_sort_with_base`].
+pub fn radix_sort(list: &mut [u32]) {
+    radix_sort_with_base(list, 256);
+}
+
+/// Sorts in place using least significant digit radix sort.
+///
+/// Split each value into digits of the given `base`, then stably sort the list
+/// by each digit in turn, from least to most significant, using a counting
+/// sort pass. Since each pass is stable, values with equal higher digits stay
+/// in the order established by the lower digits, so after the last pass the
+/// list is sorted. A base of 256 sorts a byte per pass.
+///
+/// All passes share one scratch buffer of length n and one table of `base`
+/// counts.
+///
+/// Panics if `base` is less than 2.
+///
+/// Worst-case performance: O(d(n + b)), for d digits of base b.
+/// Best-case performance: O(d(n + b)).
+/// Worst-case space complexity: O(n + b) total, O(n + b) auxiliary.
+pub fn radix_sort_with_base(list: &mut [u32], base: u32) {
+    assert!(base >= 2, "radix sort base must be at least 2");
+
+    let Some(&max) = list.iter().max() else {
+        return;
+    };
+
+    let base = u64::from(base);
+    let mut scratch = vec![0; list.len()];
+    let mut counts = vec![0usize; base as usize];
+
+    // `place` is base^pass, kept as a u64 so it can't overflow past `u32::MAX`.
+    let mut place = 1u64;
+    while place <= u64::from(max) {
+        let digit = |value: u32| ((u64::from(value) / place) % base) as usize;
+
+        counts.fill(0);
+        for &value in list.iter() {
+            counts[digit(value)] += 1;
+        }
+
+        // Turn the counts into the starting index of each digit's bucket.
+        let mut start = 0;
+        for count in counts.iter_mut() {
+            let n = *count;
+            *count = start;
+            start += n;
+        }
+
+        // Iterating in order keeps each pass stable.
+        for &value in list.iter() {
+            let d = digit(value);
+            scratch[counts[d]] = value;
+            counts[d] += 1;
+        }
+        list.copy_from_slice(&scratch);
+
+        place *= base;
+    }
+}
+
+/// Sorts floats in [0, 1) in place using bucket sort.
+///
+/// Split the range [0, 1) into n equal width buckets, one per element, and
+/// distribute the elements into them by value, so every element of a bucket is
+/// less than every element of the next. Sort each bucket using insertion sort,
+/// then concatenate them back into the list.
+///
+/// When the values are uniformly distributed each bucket holds O(1) elements
+/// on average, so the sort runs in linear time. When they're clustered, many
+/// share a bucket, and insertion sort brings it back to quadratic.
+///
+/// Panics if any value is outside [0, 1), including NaN.
+///
+/// Worst-case performance: O(n^2).
+/// Average performance: O(n).
+/// Best-case performance: O(n).
+/// Worst-case space complexity: O(n) total, O(n) auxiliary.
+pub fn bucket_sort(list: &mut [f64]) {
+    let n = list.len();
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+    for &value in list.iter() {
+        assert!((0.0..1.0).contains(&value), "value {value} is outside [0, 1)");
+        // `value * n` is below n, but may round up to it for values just below 1.
+        let bucket = ((value * n as f64) as usize).min(n - 1);
+        buckets[bucket].push(value);
+    }
+
+    let mut i = 0;
+    for bucket in &mut buckets {
+        insertion_sort(bucket);
+        list[i..i + bucket.len()].copy_from_slice(bucket);
+        i += bucket.len();
+    }
+}
+
+/// Sorts floats in place using merge sort, with a total order that includes
+/// NaN.
+///
+/// The other sorts rely on `PartialOrd`, under which NaN is unordered with
+/// every value, so a NaN leaves them with no well-defined result. Here
+/// [`f64::total_cmp`] orders values as follows:
+///
+/// -NaN < -inf < ... < -0.0 < +0.0 < ... < +inf < +NaN
+///
+/// I.e. NaNs with the sign bit set sort to the start, all other NaNs sort to
+/// the end. `-0.0` sorts before `0.0`.
+pub fn sort_floats(list: &mut [f64]) {
+    merge_sort_by(list, f64::total_cmp);
+}
+
+/// Returns a sorted copy of `list`, leaving `list` itself untouched, using
+/// merge sort.
+///
+/// See [`merge_sort`].
+pub fn sorted<T: Ord + Clone>(list: &[T]) -> Vec<T> {
+    sorted_by(list, T::cmp)
+}
+
+/// Returns a copy of `list` sorted with `cmp`, leaving `list` itself untouched,
+/// using merge sort.
+///
+/// See [`merge_sort_by`].
+pub fn sorted_by<T, F>(list: &[T], cmp: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut copy = list.to_vec();
+    merge_sort_by(&mut copy, cmp);
+    copy
+}
+
+/// Sorts in place, keeping equal elements in their original relative order,
+/// using [`merge_sort`].
+///
+/// See the [module documentation](self) for the trade-off with
+/// [`sort_unstable`].
+pub fn sort_stable<T: Ord + Clone>(list: &mut [T]) {
+    merge_sort(list);
+}
+
+/// Sorts in place, without cloning or allocating, but possibly reordering
+/// equal elements, using [`intro_sort`].
+///
+/// See the [module documentation](self) for the trade-off with
+/// [`sort_stable`].
+pub fn sort_unstable<T: Ord>(list: &mut [T]) {
+    intro_sort(list);
+}
+
+/// Returns `true` if `list` is in non-decreasing order.
+///
+/// Empty and single element lists are sorted. Elements that are unordered with
+/// each other, such as NaN and any float, are out of order, as with
+/// [`slice::is_sorted`].
+///
+/// Worst-case performance: O(n) comparisons.
+pub fn is_sorted<T: PartialOrd>(list: &[T]) -> bool {
+    first_unsorted_index(list).is_none()
+}
+
+/// Returns the index of the first element that's smaller than the one before
+/// it, or `None` if `list` is in non-decreasing order.
+///
+/// Everything before the returned index is sorted, so e.g. an insertion sort
+/// can start from there.
+///
+/// Worst-case performance: O(n) comparisons.
+pub fn first_unsorted_index<T: PartialOrd>(list: &[T]) -> Option<usize> {
+    list.windows(2)
+        .position(|w| matches!(w[0].partial_cmp(&w[1]), Some(Ordering::Greater) | None))
+        .map(|i| i + 1)
+}
+
+/// Splits `sorted` into its runs of consecutive elements with equal keys, as
+/// extracted by `key`.
+///
+/// `sorted` should already be sorted by `key`, e.g. with [`merge_sort_by_key`],
+/// so each key forms a single group. Otherwise a key appears in one group per
+/// run of it. Since the groups are subslices of `sorted`, elements keep their
+/// order within a group.
+///
+/// Worst-case performance: O(n) key extractions.
+pub fn group_sorted_by_key<T, K, F>(sorted: &[T], key: F) -> Vec<&[T]>
+where
+    K: Eq,
+    F: Fn(&T) -> K,
+{
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for i in 1..sorted.len() {
+        if key(&sorted[i - 1]) != key(&sorted[i]) {
+            groups.push(&sorted[start..i]);
+            start = i;
+        }
+    }
+    if start < sorted.len() {
+        groups.push(&sorted[start..]);
+    }
+    groups
+}
+
+/// A comparison sort, for code generic over which algorithm sorts.
+///
+/// Each implementor is a zero-sized marker type delegating to the free function
+/// of the same name, e.g. `MergeSort::sort(list)` is `merge_sort(list)`.
+///
+/// Elements must be `Clone` since the merge sorts copy them into a buffer.
+pub trait Sorter {
+    /// Sorts `list` in place, ascending.
+    fn sort<T: Ord + Clone>(list: &mut [T]);
+}
+
+/// [`bubble_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BubbleSort;
+
+impl Sorter for BubbleSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        bubble_sort(list);
+    }
+}
+
+/// [`cocktail_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CocktailSort;
+
+impl Sorter for CocktailSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        cocktail_sort(list);
+    }
+}
+
+/// [`comb_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CombSort;
+
+impl Sorter for CombSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        comb_sort(list);
+    }
+}
+
+/// [`insertion_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InsertionSort;
+
+impl Sorter for InsertionSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        insertion_sort(list);
+    }
+}
+
+/// [`binary_insertion_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinaryInsertionSort;
+
+impl Sorter for BinaryInsertionSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        binary_insertion_sort(list);
+    }
+}
+
+/// [`gnome_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GnomeSort;
+
+impl Sorter for GnomeSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        gnome_sort(list);
+    }
+}
+
+/// [`selection_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SelectionSort;
+
+impl Sorter for SelectionSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        selection_sort(list);
+    }
+}
+
+/// [`cycle_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CycleSort;
+
+impl Sorter for CycleSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        cycle_sort(list);
+    }
+}
+
+/// [`pancake_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PancakeSort;
+
+impl Sorter for PancakeSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        pancake_sort(list);
+    }
+}
+
+/// [`shell_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ShellSort;
+
+impl Sorter for ShellSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        shell_sort(list);
+    }
+}
+
+/// [`merge_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MergeSort;
+
+impl Sorter for MergeSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        merge_sort(list);
+    }
+}
+
+/// [`merge_sort_iterative`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MergeSortIterative;
+
+impl Sorter for MergeSortIterative {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        merge_sort_iterative(list);
+    }
+}
+
+/// [`natural_merge_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NaturalMergeSort;
+
+impl Sorter for NaturalMergeSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        natural_merge_sort(list);
+    }
+}
+
+/// [`quick_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuickSort;
+
+impl Sorter for QuickSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        quick_sort(list);
+    }
+}
+
+/// [`quick_sort_three_way`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuickSortThreeWay;
+
+impl Sorter for QuickSortThreeWay {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        quick_sort_three_way(list);
+    }
+}
+
+/// [`heap_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeapSort;
+
+impl Sorter for HeapSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        heap_sort(list);
+    }
+}
+
+/// [`intro_sort`] as a [`Sorter`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IntroSort;
+
+impl Sorter for IntroSort {
+    fn sort<T: Ord + Clone>(list: &mut [T]) {
+        intro_sort(list);
+    }
+}
+
+/// Orders `a` and `b` ascending, the default for the sorts that delegate to a
+/// `_by` variant.
+///
+/// Panics if `a` and `b` are unordered, e.g. a NaN float.
+fn ascending<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap()
+}
+
+/// Orders `a` and `b` descending, for the `_desc` variants.
+///
+/// Panics if `a` and `b` are unordered, e.g. a NaN float.
+fn descending<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    ascending(b, a)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    /// Returns `n` pseudo-random values from a fixed-seed xorshift generator.
+    fn random_u32s(n: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..n)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state
+            })
+            .collect()
+    }
+
+    /// A sort, by name.
+    pub(super) type NamedSort<T> = (&'static str, fn(&mut [T]));
+
+    /// Every sort over `i32`.
+    pub(super) const SORTS: [NamedSort<i32>; 23] = [
+        ("bubble_sort", bubble_sort),
+        ("cocktail_sort", cocktail_sort),
+        ("comb_sort", comb_sort),
+        ("insertion_sort", insertion_sort),
+        ("binary_insertion_sort", binary_insertion_sort),
+        ("gnome_sort", gnome_sort),
+        ("selection_sort", selection_sort),
+        ("cycle_sort", cycle_sort),
+        ("pancake_sort", pancake_sort),
+        ("shell_sort", shell_sort),
+        ("merge_sort", merge_sort),
+        ("merge_sort_iterative", merge_sort_iterative),
+        ("natural_merge_sort", natural_merge_sort),
+        ("par_merge_sort", par_merge_sort),
+        ("quick_sort", quick_sort),
+        ("quick_sort_three_way", quick_sort_three_way),
+        ("heap_sort", heap_sort),
+        ("intro_sort", intro_sort),
+        ("insertion_sort_by", |list| insertion_sort_by(list, i32::cmp)),
+        ("merge_sort_by", |list| merge_sort_by(list, i32::cmp)),
+        ("merge_sort_by_key", |list| merge_sort_by_key(list, |&x| x)),
+        ("quick_sort_by", |list| quick_sort_by(list, i32::cmp)),
+        ("shell_sort_with_gaps", |list| shell_sort_with_gaps(list, &[7, 3, 1])),
+    ];
+
+    /// Every sort over `u32` only.
+    pub(super) const U32_SORTS: [NamedSort<u32>; 3] = [
+        ("counting_sort", counting_sort),
+        ("radix_sort", radix_sort),
+        ("radix_sort_with_base", |list| radix_sort_with_base(list, 10)),
+    ];
+
+    /// Asserts that `sorted` is non-decreasing, and a permutation of
+    /// `original`, i.e. no elements were lost or duplicated.
+    fn assert_sorted_permutation<T: Ord + Clone + Debug>(original: &[T], sorted: &[T]) {
+        if let Some(i) = sorted.windows(2).position(|w| w[0] > w[1]) {
+            panic!("not sorted at index {}: {:?}", i, sorted);
+        }
+
+        let mut expected = original.to_vec();
+        expected.sort();
+        assert_eq!(expected, sorted, "not a permutation of {:?}", original);
+    }
+
+    /// A `(key, index)` pair that compares by key only, so the sorts relying on
+    /// `PartialOrd` see pairs with equal keys as equal.
+    #[derive(Debug, Clone, Copy)]
+    struct Keyed(u8, usize);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    /// Sorts `(key, index)` pairs by key only, using a `PartialOrd` sort.
+    fn sort_by_key_only(list: &mut [(u8, usize)], sort: fn(&mut [Keyed])) {
+        let mut keyed: Vec<Keyed> = list.iter().map(|&(k, i)| Keyed(k, i)).collect();
+        sort(&mut keyed);
+        for (pair, Keyed(k, i)) in list.iter_mut().zip(keyed) {
+            *pair = (k, i);
+        }
+    }
+
+    /// Returns `true` if `sort`, which must order `(key, index)` pairs by key
+    /// only, keeps pairs with equal keys in their original order.
+    ///
+    /// The input is pseudo-random keys drawn from a small range, so there are
+    /// many equal keys, each tagged with its original index.
+    pub fn is_stable(sort: fn(&mut [(u8, usize)])) -> bool {
+        let mut list: Vec<(u8, usize)> =
+            random_u32s(500).into_iter().enumerate().map(|(i, x)| ((x % 8) as u8, i)).collect();
+        sort(&mut list);
+        list.windows(2).all(|w| {
+            assert!(w[0].0 <= w[1].0, "not sorted by key");
+            w[0].0 < w[1].0 || w[0].1 < w[1].1
+        })
+    }
+
+    #[test]
+    fn test_stable_sorts() {
+        assert!(is_stable(|list| sort_by_key_only(list, bubble_sort)));
+        assert!(is_stable(|list| sort_by_key_only(list, cocktail_sort)));
+        assert!(is_stable(|list| sort_by_key_only(list, insertion_sort)));
+        assert!(is_stable(|list| insertion_sort_by(list, |a, b| a.0.cmp(&b.0))));
+        assert!(is_stable(|list| sort_by_key_only(list, binary_insertion_sort)));
+        assert!(is_stable(|list| sort_by_key_only(list, gnome_sort)));
+        assert!(is_stable(|list| sort_by_key_only(list, merge_sort)));
+        assert!(is_stable(|list| merge_sort_by(list, |a, b| a.0.cmp(&b.0))));
+        assert!(is_stable(|list| merge_sort_by_key(list, |&(k, _)| k)));
+        assert!(is_stable(|list| sort_by_key_only(list, merge_sort_iterative)));
+        assert!(is_stable(|list| sort_by_key_only(list, natural_merge_sort)));
+    }
+
+    #[test]
+    fn test_unstable_sorts() {
+        // Not a guarantee of instability, but these inputs expose it.
+        assert!(!is_stable(|list| sort_by_key_only(list, comb_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, selection_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, pancake_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, shell_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, quick_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, heap_sort)));
+        assert!(!is_stable(|list| sort_by_key_only(list, intro_sort)));
+    }
+
+    #[test]
+    fn test_sorts_random() {
+        let values = random_u32s(100 * 100);
+        for (i, chunk) in values.chunks(100).enumerate() {
+            // Vary the length, and the number of distinct values.
+            let len = i % chunk.len();
+            let original: Vec<u32> = chunk[..len].iter().map(|x| x % (i as u32 + 1)).collect();
+
+            let signed: Vec<i32> = original.iter().map(|&x| x as i32 - 50).collect();
+            for (_, sort) in SORTS {
+                let mut list = signed.clone();
+                sort(&mut list);
+                assert_sorted_permutation(&signed, &list);
+            }
+            for (_, sort) in U32_SORTS {
+                let mut list = original.clone();
+                sort(&mut list);
+                assert_sorted_permutation(&original, &list);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sorters() {
+        let sorters: [fn(&mut [u32]); 17] = [
+            BubbleSort::sort,
+            CocktailSort::sort,
+            CombSort::sort,
+            InsertionSort::sort,
+            BinaryInsertionSort::sort,
+            GnomeSort::sort,
+            SelectionSort::sort,
+            CycleSort::sort,
+            PancakeSort::sort,
+            ShellSort::sort,
+            MergeSort::sort,
+            MergeSortIterative::sort,
+            NaturalMergeSort::sort,
+            QuickSort::sort,
+            QuickSortThreeWay::sort,
+            HeapSort::sort,
+            IntroSort::sort,
+        ];
+
+        let input: Vec<u32> = random_u32s(1000).into_iter().map(|x| x % 100).collect();
+        let mut expected = input.clone();
+        expected.sort();
+        for sort in sorters {
+            let mut list = input.clone();
+            sort(&mut list);
+            assert_eq!(expected, list);
+        }
+    }
+
+    #[test]
+    fn test_bubble_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        bubble_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
+        let original = list2.to_vec();
+        bubble_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        bubble_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        bubble_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        bubble_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        bubble_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        bubble_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_bubble_sort_counted() {
+        // The example traced in the `insertion_sort` docs.
+        let list1 = &mut [5, 1, 2, 6, 3];
+        let stats = bubble_sort_counted(list1);
+        assert_eq!(&mut [1, 2, 3, 5, 6], list1);
+        assert_eq!(SortStats { comparisons: 9, swaps: 4 }, stats);
+
+        let list2 = &mut [1, 2, 3, 5, 6];
+        let stats = bubble_sort_counted(list2);
+        assert_eq!(SortStats { comparisons: 4, swaps: 0 }, stats);
+
+        // Without shrinking, each of the n passes through reverse sorted input
+        // makes n - 1 comparisons. Shrinking makes n - 1, n - 2, ..., 1.
+        let n = 100;
+        let list3: &mut Vec<i32> = &mut (0..n as i32).rev().collect();
+        let stats = bubble_sort_counted(list3);
+        assert_eq!(n * (n - 1) / 2, stats.comparisons);
+        assert!(stats.comparisons < n * (n - 1));
+    }
+
+    #[test]
+    fn test_cocktail_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        cocktail_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        cocktail_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        cocktail_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        cocktail_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        cocktail_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        cocktail_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        cocktail_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_cocktail_sort_turtle() {
+        // The smallest element starts at the far right.
+        let n = 100;
+        let turtle: Vec<i32> = (1..n).chain([0]).collect();
+
+        let list1 = &mut turtle.clone();
+        let stats = cocktail_sort_counted(list1);
+        assert_eq!(&(0..n).collect::<Vec<_>>(), list1);
+        // One forward pass, one backward pass carrying the turtle home, and one
+        // forward pass finding nothing to swap.
+        assert_eq!(n as usize - 1, stats.swaps);
+        assert!(stats.comparisons < 3 * n as usize);
+
+        // Bubble sort only moves the turtle one position per pass through.
+        let list2 = &mut turtle.clone();
+        let bubble_stats = bubble_sort_counted(list2);
+        assert_eq!(stats.swaps, bubble_stats.swaps);
+        assert!(bubble_stats.comparisons > 10 * stats.comparisons);
+    }
+
+    #[test]
+    fn test_comb_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        comb_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        comb_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        comb_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        comb_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        comb_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        comb_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        comb_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_comb_sort_counted() {
+        let reversed: Vec<i32> = (0..1000).rev().collect();
+
+        let list1 = &mut reversed.clone();
+        let stats = comb_sort_counted(list1);
+        assert_eq!(&(0..1000).collect::<Vec<_>>(), list1);
+
+        // Bubble sort needs a pass through per element, comb sort a pass through
+        // per gap.
+        let list2 = &mut reversed.clone();
+        let bubble_stats = bubble_sort_counted(list2);
+        assert!(bubble_stats.comparisons > 10 * stats.comparisons);
+        assert!(bubble_stats.swaps > 10 * stats.swaps);
+    }
+
+    #[test]
+    fn test_insertion_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        insertion_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
+        let original = list2.to_vec();
+        insertion_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        insertion_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        insertion_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        insertion_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        insertion_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        insertion_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_insertion_sort_counted() {
+        // The example traced in the `insertion_sort` docs.
+        let list1 = &mut [5, 1, 2, 6, 3];
+        let stats = insertion_sort_counted(list1);
+        assert_eq!(&mut [1, 2, 3, 5, 6], list1);
+        assert_eq!(SortStats { comparisons: 7, swaps: 4 }, stats);
+
+        let list2 = &mut [1, 2, 3, 5, 6];
+        let stats = insertion_sort_counted(list2);
+        assert_eq!(SortStats { comparisons: 4, swaps: 0 }, stats);
+    }
+
+    #[test]
+    fn test_insertion_sort_by() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        insertion_sort_by(list1, |a, b| b.cmp(a));
+        assert_eq!(&mut [11, 9, 8, 6, 3, 3, 2, 2, 1, 1], list1);
+
+        let list2 = &mut [("c", 3), ("a", 1), ("b", 2)];
+        insertion_sort_by(list2, |a, b| a.1.cmp(&b.1));
+        assert_eq!(&mut [("a", 1), ("b", 2), ("c", 3)], list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        insertion_sort_by(list3, |a, b| b.partial_cmp(a).unwrap());
+        assert_eq!(&mut [10.5, 1.01, 1.00, 0.8, 0.001], list3);
+    }
+
+    #[test]
+    fn test_binary_insertion_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        binary_insertion_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
+        let original = list2.to_vec();
+        binary_insertion_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut ["Test", "A old day", "A new day"];
+        let original = list3.to_vec();
+        binary_insertion_sort(list3);
+        assert_sorted_permutation(&original, list3);
+
+        let list4: &mut [i32] = &mut [];
+        binary_insertion_sort(list4);
+        assert!(list4.is_empty());
+    }
+
+    #[test]
+    fn test_binary_insertion_sort_counted() {
+        // Every element must move to the front, past every sorted element.
+        let n = 1000;
+        let reversed: Vec<i32> = (0..n).rev().collect();
+
+        let mut list1 = reversed.clone();
+        let linear = insertion_sort_counted(&mut list1);
+        let mut list2 = reversed;
+        let binary = binary_insertion_sort_counted(&mut list2);
+        assert_eq!(list1, list2);
+
+        assert!(binary.comparisons < linear.comparisons);
+        assert!(binary.comparisons <= n as usize * n.ilog2() as usize);
+        // The shifting is no cheaper.
+        assert_eq!(linear.swaps, binary.swaps);
+
+        // Sorted input still costs a search per element, but no shifting.
+        let list3 = &mut [1, 2, 3, 5, 6];
+        let stats = binary_insertion_sort_counted(list3);
+        assert_eq!(0, stats.swaps);
+    }
+
+    #[test]
+    fn test_gnome_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        gnome_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        gnome_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        gnome_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        gnome_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        gnome_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        gnome_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        gnome_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_selection_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        selection_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
+        let original = list2.to_vec();
+        selection_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        selection_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        selection_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        selection_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        selection_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        selection_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_cycle_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        cycle_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        cycle_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        cycle_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        cycle_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        cycle_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        cycle_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        cycle_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_cycle_sort_counted() {
+        let list1 = &mut [5, 1, 2, 6, 3];
+        assert_eq!(5, cycle_sort_counted(list1).swaps);
+        assert_eq!(&mut [1, 2, 3, 5, 6], list1);
+
+        // Only the two swapped elements are out of place.
+        let list2: &mut Vec<i32> = &mut (0..1000).collect();
+        list2.swap(400, 401);
+        assert_eq!(2, cycle_sort_counted(list2).swaps);
+        assert_eq!(&(0..1000).collect::<Vec<_>>(), list2);
+
+        assert_eq!(0, cycle_sort_counted(list2).swaps);
+
+        // Every element out of its final position is written exactly once.
+        let input: Vec<u32> = random_u32s(500).into_iter().map(|x| x % 50).collect();
+        let mut expected = input.clone();
+        expected.sort();
+        let misplaced = input.iter().zip(&expected).filter(|(a, b)| a != b).count();
+        let mut list3 = input;
+        assert_eq!(misplaced, cycle_sort_counted(&mut list3).swaps);
+        assert_eq!(expected, list3);
+    }
+
+    #[test]
+    fn test_pancake_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        pancake_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        pancake_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        pancake_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        pancake_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        pancake_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        pancake_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        pancake_sort(list7);
+        assert_sorted_permutation(&original, list7);
+    }
+
+    #[test]
+    fn test_pancake_sort_flips() {
+        let list1 = &mut [3, 1, 2];
+        // 3 1 2 -> 2 1 3 -> 1 2 3
+        assert_eq!(vec![3, 2], pancake_sort_flips(list1));
+        assert_eq!(&mut [1, 2, 3], list1);
+
+        let list2: &mut Vec<i32> = &mut (0..100).collect();
+        assert!(pancake_sort_flips(list2).is_empty());
+
+        // Replaying the flips on the original sorts it too.
+        let original: Vec<u32> = random_u32s(200).into_iter().map(|x| x % 50).collect();
+        let mut list3 = original.clone();
+        let flips = pancake_sort_flips(&mut list3);
+        assert!(flips.len() <= 2 * (original.len() - 1));
+
+        let mut replayed = original.clone();
+        for k in flips {
+            replayed[..k].reverse();
+        }
+        assert_sorted_permutation(&original, &replayed);
+        assert_eq!(list3, replayed);
+    }
+
+    #[test]
+    fn test_shell_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        shell_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, 9, -1, 2, 3, 1];
+        let original = list2.to_vec();
+        shell_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        shell_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        shell_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        shell_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        shell_sort(list6);
+        assert!(list6.is_empty());
+
+        let mut list7 = random_u32s(1000);
+        let mut expected = list7.clone();
+        insertion_sort(&mut expected);
+        shell_sort(&mut list7);
+        assert_eq!(expected, list7);
+
+        // Ciura's sequence.
+        let mut list8 = random_u32s(1000);
+        shell_sort_with_gaps(&mut list8, &[701, 301, 132, 57, 23, 10, 4, 1]);
+        assert_eq!(expected, list8);
+    }
+
+    #[test]
+    #[should_panic(expected = "must end with 1")]
+    fn test_shell_sort_with_gaps_not_ending_with_one() {
+        shell_sort_with_gaps(&mut [3, 2, 1], &[4, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly decreasing")]
+    fn test_shell_sort_with_gaps_not_decreasing() {
+        shell_sort_with_gaps(&mut [3, 2, 1], &[2, 4, 1]);
+    }
+
+    #[test]
+    fn test_merge_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        merge_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        merge_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        merge_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        merge_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        merge_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6 = &mut [String::from("banana"), String::from("apple")];
+        let original = list6.to_vec();
+        merge_sort(list6);
+        assert_sorted_permutation(&original, list6);
+    }
+
+    #[test]
+    fn test_merge_sort_with_buffer() {
+        let mut scratch = Vec::new();
+        for len in [0, 1, 2, 10, 1000] {
+            let original: Vec<u32> = random_u32s(len).into_iter().map(|x| x % 100).collect();
+            let mut expected = original.clone();
+            merge_sort(&mut expected);
+
+            // The same buffer, reused across sorts of differing lengths.
+            let mut list = original.clone();
+            merge_sort_with_buffer(&mut list, &mut scratch);
+            assert_eq!(expected, list);
+            assert_sorted_permutation(&original, &list);
+        }
+        assert!(scratch.capacity() >= 1000);
+
+        // A buffer holding junk is cleared before use.
+        let list1 = &mut [3, 1, 2];
+        merge_sort_with_buffer(list1, &mut vec![9, 9, 9, 9]);
+        assert_eq!(&mut [1, 2, 3], list1);
+    }
+
+    #[test]
+    fn test_merge_sort_by() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        merge_sort_by(list1, |a, b| b.cmp(a));
+        assert_eq!(&mut [11, 9, 8, 6, 3, 3, 2, 2, 1, 1], list1);
+
+        let list2 = &mut [("c", 3), ("a", 1), ("b", 2)];
+        merge_sort_by(list2, |a, b| a.1.cmp(&b.1));
+        assert_eq!(&mut [("a", 1), ("b", 2), ("c", 3)], list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        merge_sort_by(list3, |a, b| b.partial_cmp(a).unwrap());
+        assert_eq!(&mut [10.5, 1.01, 1.00, 0.8, 0.001], list3);
+    }
+
+    #[test]
+    fn test_merge_sort_by_key() {
+        let list1 = &mut vec![('a', 3), ('b', -1), ('c', 2), ('d', 0)];
+        merge_sort_by_key(list1, |p| p.1);
+        assert_eq!(&mut vec![('b', -1), ('d', 0), ('c', 2), ('a', 3)], list1);
+
+        let list2 = &mut vec![('a', 3), ('b', -1), ('c', 2), ('d', 0)];
+        merge_sort_by_key(list2, |p| std::cmp::Reverse(p.1));
+        assert_eq!(&mut vec![('a', 3), ('c', 2), ('d', 0), ('b', -1)], list2);
+    }
+
+    #[test]
+    fn test_merge_sort_iterative() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        merge_sort_iterative(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        merge_sort_iterative(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        merge_sort_iterative(list3);
         assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
 
         let list4 = &mut ['a', 'c', 'b'];
-        insertion_sort(list4);
-        assert_eq!(&mut ['a', 'b', 'c'], list4);
+        let original = list4.to_vec();
+        merge_sort_iterative(list4);
+        assert_sorted_permutation(&original, list4);
 
         let list5 = &mut ["Test", "A old day", "A new day"];
-        insertion_sort(list5);
-        assert_eq!(&mut ["A new day", "A old day", "Test"], list5);
+        let original = list5.to_vec();
+        merge_sort_iterative(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6 = &mut [String::from("banana"), String::from("apple")];
+        let original = list6.to_vec();
+        merge_sort_iterative(list6);
+        assert_sorted_permutation(&original, list6);
+
+        // Not a power of two, so the last run of each pass is short.
+        let list7: &mut Vec<i32> = &mut (0..1000).rev().collect();
+        let original = list7.to_vec();
+        merge_sort_iterative(list7);
+        assert_sorted_permutation(&original, list7);
     }
 
     #[test]
-    fn test_merge_sort() {
+    fn test_natural_merge_sort() {
         let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
-        merge_sort(list1);
-        assert_eq!(&mut [1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list1);
+        let original = list1.to_vec();
+        natural_merge_sort(list1);
+        assert_sorted_permutation(&original, list1);
 
         let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
-        merge_sort(list2);
-        assert_eq!(&mut [-1, 1, 1, 2, 2, 3, 3, 6, 8, 9, 11], list2);
+        let original = list2.to_vec();
+        natural_merge_sort(list2);
+        assert_sorted_permutation(&original, list2);
 
         let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
-        merge_sort(list3);
+        natural_merge_sort(list3);
         assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
 
         let list4 = &mut ['a', 'c', 'b'];
-        merge_sort(list4);
-        assert_eq!(&mut ['a', 'b', 'c'], list4);
+        let original = list4.to_vec();
+        natural_merge_sort(list4);
+        assert_sorted_permutation(&original, list4);
 
         let list5 = &mut ["Test", "A old day", "A new day"];
-        merge_sort(list5);
-        assert_eq!(&mut ["A new day", "A old day", "Test"], list5);
+        let original = list5.to_vec();
+        natural_merge_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        natural_merge_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        natural_merge_sort(list7);
+        assert_sorted_permutation(&original, list7);
+
+        let mut list8 = random_u32s(10_000);
+        let mut expected = list8.clone();
+        expected.sort();
+        natural_merge_sort(&mut list8);
+        assert_eq!(expected, list8);
+    }
+
+    #[test]
+    fn test_natural_merge_sort_counted() {
+        // Sorted input is a single run, so finding it is the only work.
+        let n = 1000;
+        let list1: &mut Vec<i32> = &mut (0..n).collect();
+        assert_eq!(n as usize - 1, natural_merge_sort_counted(list1).comparisons);
+        assert_eq!(&(0..n).collect::<Vec<_>>(), list1);
+
+        // A few runs take a few passes of at most n comparisons each.
+        let list2: &mut Vec<i32> = &mut (500..n).chain(0..250).chain(250..500).collect();
+        let comparisons = natural_merge_sort_counted(list2).comparisons;
+        assert_eq!(&(0..n).collect::<Vec<_>>(), list2);
+        assert!(comparisons < 3 * n as usize);
+
+        // Top-down merge sort splits the sorted runs and merges them back.
+        let merge_comparisons = Cell::new(0);
+        let list3: &mut Vec<i32> = &mut (500..n).chain(0..250).chain(250..500).collect();
+        merge_sort_by(list3, |a, b| {
+            merge_comparisons.set(merge_comparisons.get() + 1);
+            a.cmp(b)
+        });
+        assert!(merge_comparisons.get() > 2 * comparisons);
+    }
+
+    #[test]
+    fn test_par_merge_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        par_merge_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2: &mut [i32] = &mut [];
+        par_merge_sort(list2);
+        assert!(list2.is_empty());
+
+        // Large enough to split across threads several levels deep.
+        let mut list3 = random_u32s(1_000_000);
+        let mut expected = list3.clone();
+        merge_sort(&mut expected);
+        par_merge_sort(&mut list3);
+        assert_eq!(expected, list3);
+
+        // Just over the cutoff, so only the top level splits.
+        let mut list4: Vec<String> =
+            random_u32s(PAR_MERGE_SORT_CUTOFF + 1).iter().map(u32::to_string).collect();
+        let mut expected = list4.clone();
+        expected.sort();
+        par_merge_sort(&mut list4);
+        assert_eq!(expected, list4);
+    }
+
+    #[test]
+    fn test_k_way_merge() {
+        let runs = vec![vec![1, 4, 7, 10], vec![2, 5], vec![], vec![0, 3, 6, 8, 9, 11]];
+        assert_eq!((0..12).collect::<Vec<_>>(), k_way_merge(&runs));
+
+        let runs = vec![vec!["b", "d"], vec!["a", "c", "e"]];
+        assert_eq!(vec!["a", "b", "c", "d", "e"], k_way_merge(&runs));
+
+        let runs = vec![vec![1, 1, 2], vec![1, 2, 2]];
+        assert_eq!(vec![1, 1, 1, 2, 2, 2], k_way_merge(&runs));
+
+        let runs: Vec<Vec<i32>> = vec![vec![], vec![]];
+        assert!(k_way_merge(&runs).is_empty());
+        assert!(k_way_merge::<i32>(&[]).is_empty());
+
+        // Sorted chunks of a list merge back into the whole list, sorted.
+        let original: Vec<u32> = random_u32s(1000).into_iter().map(|x| x % 100).collect();
+        let runs: Vec<Vec<u32>> = original.chunks(70).map(sorted).collect();
+        assert_sorted_permutation(&original, &k_way_merge(&runs));
+    }
+
+    #[test]
+    fn test_quick_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        quick_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        quick_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        quick_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        quick_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        quick_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        quick_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        quick_sort(list7);
+        assert_sorted_permutation(&original, list7);
+
+        // Already sorted input is the worst case for a naive pivot choice.
+        let list8: &mut Vec<i32> = &mut (0..1000).collect();
+        let original = list8.to_vec();
+        quick_sort(list8);
+        assert_sorted_permutation(&original, list8);
+    }
+
+    #[test]
+    fn test_quick_sort_by() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        quick_sort_by(list1, |a, b| b.cmp(a));
+        assert_eq!(&mut [11, 9, 8, 6, 3, 3, 2, 2, 1, 1], list1);
+
+        let list2 = &mut [("c", 3), ("a", 1), ("b", 2)];
+        quick_sort_by(list2, |a, b| a.1.cmp(&b.1));
+        assert_eq!(&mut [("a", 1), ("b", 2), ("c", 3)], list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        quick_sort_by(list3, |a, b| b.partial_cmp(a).unwrap());
+        assert_eq!(&mut [10.5, 1.01, 1.00, 0.8, 0.001], list3);
+    }
+
+    #[test]
+    fn test_quick_sort_three_way() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        quick_sort_three_way(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        quick_sort_three_way(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        quick_sort_three_way(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        quick_sort_three_way(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        quick_sort_three_way(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        quick_sort_three_way(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        quick_sort_three_way(list7);
+        assert_sorted_permutation(&original, list7);
+
+        let list8: &mut Vec<i32> = &mut (0..1000).rev().collect();
+        let original = list8.to_vec();
+        quick_sort_three_way(list8);
+        assert_sorted_permutation(&original, list8);
+    }
+
+    #[test]
+    fn test_quick_sort_three_way_counted() {
+        let input: Vec<u32> = random_u32s(10_000).into_iter().map(|x| x % 3).collect();
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut list1 = input.clone();
+        let stats = quick_sort_three_way_counted(&mut list1);
+        assert_eq!(expected, list1);
+
+        // Count the comparisons plain quicksort makes on the same input.
+        let comparisons = Cell::new(0);
+        let mut list2 = input;
+        quick_sort_by(&mut list2, |a, b| {
+            comparisons.set(comparisons.get() + 1);
+            a.cmp(b)
+        });
+        assert_eq!(expected, list2);
+
+        // Three values take at most three partitions of the whole list.
+        assert!(stats.comparisons <= 3 * (expected.len() + 3));
+        assert!(stats.comparisons * 100 < comparisons.get());
+
+        // All equal elements are placed in a single pass, without swaps.
+        let mut list3 = [7; 1000];
+        assert_eq!(
+            SortStats { comparisons: 1003, swaps: 0 },
+            quick_sort_three_way_counted(&mut list3)
+        );
+    }
+
+    #[test]
+    fn test_partition() {
+        /// Asserts `list` is partitioned around its element at `p`.
+        fn assert_partitioned<T: PartialOrd + Debug>(list: &[T], p: usize) {
+            assert!(list[..p].iter().all(|x| *x < list[p]), "{list:?} at {p}");
+            assert!(list[p + 1..].iter().all(|x| *x >= list[p]), "{list:?} at {p}");
+        }
+
+        let list1 = &mut [5, 1, 9, 3, 7, 2, 8];
+        let p = partition(list1, 0);
+        assert_eq!(3, p);
+        assert_eq!(5, list1[p]);
+        assert_partitioned(list1, p);
+
+        // The smallest and largest pivots land at either end.
+        let list2 = &mut [5, 1, 9, 3, 7, 2, 8];
+        assert_eq!(0, partition(list2, 1));
+        assert_partitioned(list2, 0);
+        assert_eq!(6, partition(list2, 2));
+        assert_eq!(9, list2[6]);
+
+        // Elements equal to the pivot go to the rhs.
+        let list3 = &mut [2, 2, 1, 2, 3];
+        let p = partition(list3, 3);
+        assert_eq!(1, p);
+        assert_partitioned(list3, p);
+
+        let list4 = &mut ["c", "a", "d", "b"];
+        let p = partition(list4, 0);
+        assert_eq!(2, p);
+        assert_partitioned(list4, p);
+
+        assert_eq!(0, partition(&mut [42], 0));
+
+        let original: Vec<u32> = random_u32s(1000).into_iter().map(|x| x % 100).collect();
+        for pivot_index in [0, 1, 500, 999] {
+            let mut list = original.clone();
+            let p = partition(&mut list, pivot_index);
+            assert_eq!(original[pivot_index], list[p]);
+            assert_eq!(original.iter().filter(|&&x| x < original[pivot_index]).count(), p);
+            assert_partitioned(&list, p);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of bounds")]
+    fn test_partition_out_of_bounds() {
+        partition(&mut [1, 2, 3], 3);
+    }
+
+    #[test]
+    fn test_quickselect() {
+        let list = [9, 4, 7, 1, 8, 2, 6, 3, 5, 0, 4];
+        let mut sorted = list;
+        sorted.sort();
+
+        for k in 0..list.len() {
+            let mut list1 = list;
+            assert_eq!(Some(sorted[k]), quickselect(&mut list1, k));
+            assert_eq!(sorted[k], list1[k]);
+        }
+
+        let mut list2 = list;
+        assert_eq!(Some(0), quickselect(&mut list2, 0));
+        assert_eq!(Some(9), quickselect(&mut list2, list.len() - 1));
+        assert_eq!(Some(4), quickselect(&mut list2, list.len() / 2));
+        assert_eq!(None, quickselect(&mut list2, list.len()));
+
+        let list3 = &mut ["Test", "A old day", "A new day"];
+        assert_eq!(Some("A old day"), quickselect(list3, 1));
+
+        let list4: &mut [i32] = &mut [];
+        assert_eq!(None, quickselect(list4, 0));
+    }
+
+    #[test]
+    fn test_nth_element() {
+        let list = [9, 4, 7, 1, 8, 2, 6, 3, 5, 0, 4];
+        let mut sorted = list;
+        sorted.sort();
+
+        for n in 0..list.len() {
+            let mut list1 = list;
+            nth_element(&mut list1, n);
+            assert_eq!(sorted[n], list1[n]);
+            assert!(list1[..n].iter().all(|x| *x <= list1[n]));
+            assert!(list1[n + 1..].iter().all(|x| *x >= list1[n]));
+            list1.sort();
+            assert_eq!(sorted, list1);
+        }
+
+        let list2: Vec<u32> = random_u32s(1000).into_iter().map(|x| x % 100).collect();
+        let mut sorted = list2.clone();
+        sorted.sort();
+        for n in [0, 1, 250, 499, 500, 998, 999] {
+            let mut list3 = list2.clone();
+            nth_element(&mut list3, n);
+            assert_eq!(sorted[n], list3[n]);
+            assert!(list3[..n].iter().all(|x| *x <= list3[n]));
+            assert!(list3[n + 1..].iter().all(|x| *x >= list3[n]));
+        }
+
+        let list4 = &mut [1.5, -0.5, 3.0];
+        nth_element(list4, 1);
+        assert_eq!(1.5, list4[1]);
+
+        let list5 = &mut ["only"];
+        nth_element(list5, 0);
+        assert_eq!(&mut ["only"], list5);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of bounds")]
+    fn test_nth_element_out_of_bounds() {
+        nth_element(&mut [3, 1, 2], 3);
+    }
+
+    #[test]
+    fn test_heap_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        heap_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        heap_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        heap_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        heap_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        heap_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut Vec<i32> = &mut (0..1000).rev().collect();
+        let original = list6.to_vec();
+        heap_sort(list6);
+        assert_sorted_permutation(&original, list6);
+    }
+
+    #[test]
+    fn test_intro_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        intro_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let original = list2.to_vec();
+        intro_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        intro_sort(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+
+        let list4 = &mut ['a', 'c', 'b'];
+        let original = list4.to_vec();
+        intro_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5 = &mut ["Test", "A old day", "A new day"];
+        let original = list5.to_vec();
+        intro_sort(list5);
+        assert_sorted_permutation(&original, list5);
+
+        let list6: &mut [i32] = &mut [];
+        intro_sort(list6);
+        assert!(list6.is_empty());
+
+        let list7 = &mut [42];
+        let original = list7.to_vec();
+        intro_sort(list7);
+        assert_sorted_permutation(&original, list7);
+
+        let mut list8 = random_u32s(10_000);
+        let mut expected = list8.clone();
+        expected.sort();
+        intro_sort(&mut list8);
+        assert_eq!(expected, list8);
+    }
+
+    /// An element that counts every comparison made against it.
+    #[derive(Debug, Clone)]
+    struct Counting<'a>(i32, &'a Cell<usize>);
+
+    impl PartialEq for Counting<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.1.set(self.1.get() + 1);
+            self.0 == other.0
+        }
+    }
+
+    impl PartialOrd for Counting<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.1.set(self.1.get() + 1);
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn test_intro_sort_adversarial() {
+        // Every partition of equal elements peels off only the pivot, so
+        // quicksort makes n partitions rather than log n.
+        let n: usize = 5000;
+        let comparisons = Cell::new(0);
+        let equal: Vec<Counting> = (0..n).map(|_| Counting(7, &comparisons)).collect();
+
+        let mut list1 = equal.clone();
+        intro_sort(&mut list1);
+        let intro_comparisons = comparisons.replace(0);
+        let n_log_n = n * n.ilog2() as usize;
+        assert!(intro_comparisons < 10 * n_log_n);
+
+        let mut list2 = equal;
+        quick_sort(&mut list2);
+        assert!(comparisons.get() > n * n / 4);
+    }
+
+    #[test]
+    fn test_counting_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        counting_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [1, 2, 3, 5, 8, 13];
+        let original = list2.to_vec();
+        counting_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3 = &mut [7, 7, 7, 7];
+        let original = list3.to_vec();
+        counting_sort(list3);
+        assert_sorted_permutation(&original, list3);
+
+        let list4 = &mut [u32::MAX, u32::MAX - 2, u32::MAX - 1, u32::MAX - 2];
+        let original = list4.to_vec();
+        counting_sort(list4);
+        assert_sorted_permutation(&original, list4);
+
+        let list5: &mut [u32] = &mut [];
+        counting_sort(list5);
+        assert!(list5.is_empty());
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let list1 = &mut [1, 3, 2, 11, 6, 8, 9, 2, 3, 1];
+        let original = list1.to_vec();
+        radix_sort(list1);
+        assert_sorted_permutation(&original, list1);
+
+        let list2 = &mut [u32::MAX, 0, 256, 255, u32::MAX, 1, 0];
+        let original = list2.to_vec();
+        radix_sort(list2);
+        assert_sorted_permutation(&original, list2);
+
+        let list3: &mut [u32] = &mut [];
+        radix_sort(list3);
+        assert!(list3.is_empty());
+
+        let mut list4 = random_u32s(10_000);
+        list4.extend([0, u32::MAX]);
+        let mut expected = list4.clone();
+        expected.sort();
+        radix_sort(&mut list4);
+        assert_eq!(expected, list4);
+
+        let mut list5 = random_u32s(1000);
+        let mut expected = list5.clone();
+        expected.sort();
+        radix_sort_with_base(&mut list5, 10);
+        assert_eq!(expected, list5);
+    }
+
+    /// Returns `n` pseudo-random floats, uniformly distributed in [0, 1).
+    fn random_unit_f64s(n: usize) -> Vec<f64> {
+        random_u32s(n).into_iter().map(|x| f64::from(x) / (f64::from(u32::MAX) + 1.0)).collect()
+    }
+
+    #[test]
+    fn test_bucket_sort() {
+        let list1 = &mut [0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51, 0.0];
+        bucket_sort(list1);
+        assert_eq!(&mut [0.0, 0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52], list1);
+
+        let mut list2 = random_unit_f64s(10_000);
+        let mut expected = list2.clone();
+        expected.sort_by(f64::total_cmp);
+        bucket_sort(&mut list2);
+        assert_eq!(expected, list2);
+
+        // Every value falls in the first bucket.
+        let mut list3: Vec<f64> = random_unit_f64s(1000).into_iter().map(|x| x / 1e6).collect();
+        let mut expected = list3.clone();
+        expected.sort_by(f64::total_cmp);
+        bucket_sort(&mut list3);
+        assert_eq!(expected, list3);
+
+        let list4 = &mut [0.999_999_999_999_999_9, 0.5];
+        bucket_sort(list4);
+        assert_eq!(&mut [0.5, 0.999_999_999_999_999_9], list4);
+
+        let list5: &mut [f64] = &mut [];
+        bucket_sort(list5);
+        assert!(list5.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "value 1 is outside [0, 1)")]
+    fn test_bucket_sort_out_of_range() {
+        bucket_sort(&mut [0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_sorted() {
+        let list1 = [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+        let copy = sorted(&list1);
+        assert_eq!([1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1], list1);
+        assert_sorted_permutation(&list1, &copy);
+
+        let list2 = ["Test", "A old day", "A new day"];
+        assert_eq!(vec!["A new day", "A old day", "Test"], sorted(&list2));
+
+        let list3: [i32; 0] = [];
+        assert!(sorted(&list3).is_empty());
+    }
+
+    #[test]
+    fn test_sorted_by() {
+        let list1 = [('a', 3), ('b', -1), ('c', 2), ('d', 0)];
+        let copy = sorted_by(&list1, |a, b| a.1.cmp(&b.1));
+        assert_eq!([('a', 3), ('b', -1), ('c', 2), ('d', 0)], list1);
+        assert_eq!(vec![('b', -1), ('d', 0), ('c', 2), ('a', 3)], copy);
+
+        let list2 = [1.01, 1.00, 10.5, 0.8, 0.001];
+        assert_eq!(
+            vec![10.5, 1.01, 1.00, 0.8, 0.001],
+            sorted_by(&list2, |a: &f64, b| b.total_cmp(a))
+        );
+    }
+
+    #[test]
+    fn test_sort_stable_and_unstable() {
+        assert!(is_stable(|list| sort_by_key_only(list, sort_stable)));
+
+        // Only the order of the keys is guaranteed.
+        let mut list: Vec<(u8, usize)> =
+            random_u32s(500).into_iter().enumerate().map(|(i, x)| ((x % 8) as u8, i)).collect();
+        let original = list.clone();
+        sort_by_key_only(&mut list, sort_unstable);
+        assert!(list.windows(2).all(|w| w[0].0 <= w[1].0));
+        let mut expected = original;
+        expected.sort();
+        list.sort();
+        assert_eq!(expected, list);
+
+        for sort in [sort_stable, sort_unstable] {
+            let list1 = &mut [1, 3, 2, 11, 6, 8, -1, 9, 2, 3, 1];
+            let original = list1.to_vec();
+            sort(list1);
+            assert_sorted_permutation(&original, list1);
+        }
+    }
+
+    #[test]
+    fn test_is_sorted() {
+        assert!(is_sorted::<i32>(&[]));
+        assert!(is_sorted(&[42]));
+        assert!(is_sorted(&[1, 2, 2, 3, 5, 8]));
+        assert!(is_sorted(&["A new day", "A old day", "Test"]));
+        assert!(!is_sorted(&[3, 2, 1]));
+        assert!(!is_sorted(&[1.0, f64::NAN, 2.0]));
+
+        assert_eq!(None, first_unsorted_index::<i32>(&[]));
+        assert_eq!(None, first_unsorted_index(&[42]));
+        assert_eq!(None, first_unsorted_index(&[1, 2, 2, 3, 5, 8]));
+        assert_eq!(Some(1), first_unsorted_index(&[3, 2, 1]));
+
+        // Sorted except for one element near the end.
+        let mut list: Vec<i32> = (0..1000).collect();
+        list[997] = -1;
+        assert!(!is_sorted(&list));
+        assert_eq!(Some(997), first_unsorted_index(&list));
+    }
+
+    #[test]
+    fn test_desc_sorts() {
+        let sorts: [NamedSort<i32>; 4] = [
+            ("insertion_sort_desc", insertion_sort_desc),
+            ("merge_sort_desc", merge_sort_desc),
+            ("quick_sort_desc", quick_sort_desc),
+            ("heap_sort_desc", heap_sort_desc),
+        ];
+
+        for (name, sort) in sorts {
+            let list1 = &mut [3, 1, 2];
+            sort(list1);
+            assert_eq!(&mut [3, 2, 1], list1, "{name}");
+
+            let list2: &mut [i32] = &mut [];
+            sort(list2);
+            assert!(list2.is_empty(), "{name}");
+
+            let mut list3: Vec<i32> =
+                random_u32s(1000).into_iter().map(|x| (x % 100) as i32).collect();
+            let mut expected = list3.clone();
+            expected.sort_by(|a, b| b.cmp(a));
+            sort(&mut list3);
+            assert_eq!(expected, list3, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_desc_sorts_stable() {
+        let original = [Keyed(1, 0), Keyed(2, 1), Keyed(1, 2), Keyed(2, 3)];
+        let expected = [(2, 1), (2, 3), (1, 0), (1, 2)];
+
+        let list1 = &mut original.clone();
+        insertion_sort_desc(list1);
+        assert_eq!(expected, list1.map(|Keyed(k, i)| (k, i)));
+
+        let list2 = &mut original.clone();
+        merge_sort_desc(list2);
+        assert_eq!(expected, list2.map(|Keyed(k, i)| (k, i)));
+    }
+
+    #[test]
+    fn test_group_sorted_by_key() {
+        let list1 = [(1, 'a'), (1, 'b'), (2, 'c')];
+        let groups = group_sorted_by_key(&list1, |p| p.0);
+        assert_eq!(vec![&[(1, 'a'), (1, 'b')][..], &[(2, 'c')][..]], groups);
+        assert_eq!(vec![2, 1], groups.iter().map(|g| g.len()).collect::<Vec<_>>());
+
+        let list2 = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let groups = group_sorted_by_key(&list2, |s| s.chars().next());
+        assert_eq!(vec![&list2[..2], &list2[2..4], &list2[4..]], groups);
+
+        // Every element in its own group, or all in one.
+        assert_eq!(3, group_sorted_by_key(&[1, 2, 3], |&x| x).len());
+        assert_eq!(vec![&[1, 2, 3][..]], group_sorted_by_key(&[1, 2, 3], |_| ()));
+
+        let list3: [i32; 0] = [];
+        assert!(group_sorted_by_key(&list3, |&x| x).is_empty());
+
+        // Sorting by the key first brings each key's elements together.
+        let mut list4: Vec<(u8, usize)> =
+            random_u32s(100).into_iter().enumerate().map(|(i, x)| ((x % 5) as u8, i)).collect();
+        merge_sort_by_key(&mut list4, |p| p.0);
+        let groups = group_sorted_by_key(&list4, |p| p.0);
+        assert_eq!(5, groups.len());
+        for group in groups {
+            assert!(group.iter().all(|p| p.0 == group[0].0));
+            assert!(group.windows(2).all(|w| w[0].1 < w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_sort_floats() {
+        let list1 = &mut [1.0, f64::NAN, -2.0, 0.0];
+        sort_floats(list1);
+        assert_eq!(&[-2.0, 0.0, 1.0], &list1[..3]);
+        assert!(list1[3].is_nan());
+
+        let list2 = &mut [f64::INFINITY, f64::NAN, 0.0, -f64::NAN, -0.0, f64::NEG_INFINITY];
+        sort_floats(list2);
+        assert!(list2[0].is_nan() && list2[0].is_sign_negative());
+        assert_eq!(f64::NEG_INFINITY, list2[1]);
+        assert!(list2[2] == 0.0 && list2[2].is_sign_negative());
+        assert!(list2[3] == 0.0 && list2[3].is_sign_positive());
+        assert_eq!(f64::INFINITY, list2[4]);
+        assert!(list2[5].is_nan() && list2[5].is_sign_positive());
+
+        let list3 = &mut [1.01, 1.00, 10.5, 0.8, 0.001];
+        sort_floats(list3);
+        assert_eq!(&mut [0.001, 0.8, 1.00, 1.01, 10.5], list3);
+    }
+}
+
+#[cfg(test)]
+mod prop_test {
+    use proptest::prelude::*;
+    use proptest::test_runner::{TestError, TestRunner};
+
+    use super::test::{SORTS, U32_SORTS};
+
+    proptest! {
+        // Matching the standard library's output also means the elements are a
+        // permutation of the input, i.e. none were lost or duplicated.
+        #[test]
+        fn prop_sorts_match_std(list in prop::collection::vec(any::<i32>(), 0..200)) {
+            let mut expected = list.clone();
+            expected.sort();
+            for (name, sort) in SORTS {
+                let mut actual = list.clone();
+                sort(&mut actual);
+                prop_assert_eq!(&expected, &actual, "{}", name);
+            }
+        }
+
+        #[test]
+        fn prop_u32_sorts_match_std(list in prop::collection::vec(0..100_000u32, 0..200)) {
+            let mut expected = list.clone();
+            expected.sort();
+            for (name, sort) in U32_SORTS {
+                let mut actual = list.clone();
+                sort(&mut actual);
+                prop_assert_eq!(&expected, &actual, "{}", name);
+            }
+        }
+
+        #[test]
+        fn prop_sorts_few_unique(list in prop::collection::vec(0..4i32, 0..200)) {
+            let mut expected = list.clone();
+            expected.sort();
+            for (name, sort) in SORTS {
+                let mut actual = list.clone();
+                sort(&mut actual);
+                prop_assert_eq!(&expected, &actual, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_prop_shrinks_to_minimal_input() {
+        // A broken bubble sort whose single pass through never reaches the last
+        // element.
+        fn broken_sort(list: &mut [i32]) {
+            for i in 0..list.len().saturating_sub(2) {
+                if list[i] > list[i + 1] {
+                    list.swap(i, i + 1);
+                }
+            }
+        }
+
+        let mut runner = TestRunner::deterministic();
+        let result = runner.run(&prop::collection::vec(any::<i32>(), 0..200), |list| {
+            let mut expected = list.clone();
+            expected.sort();
+            let mut actual = list;
+            broken_sort(&mut actual);
+            prop_assert_eq!(expected, actual);
+            Ok(())
+        });
+
+        // The smallest failing input is an out of order pair, and shrinking
+        // finds one regardless of how large the first failure was.
+        match result {
+            Err(TestError::Fail(_, list)) => {
+                assert_eq!(2, list.len());
+                assert!(list[0] > list[1]);
+            }
+            result => panic!("expected the broken sort to fail, got {:?}", result),
+        }
     }
 }
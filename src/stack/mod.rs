@@ -0,0 +1,128 @@
+//! # stack data structures.
+//!
+//! `stack` defines a stack data structure on generic types, and algorithms on
+//! it.
+
+/// A last-in, first-out stack.
+///
+/// Backed by a `Vec`, with the top of the stack at the end.
+///
+/// Push: O(1) amortised.
+/// Pop: O(1).
+/// Peek: O(1).
+#[derive(Debug, Clone, Default)]
+pub struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Pushes `item` onto the top of the stack.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Removes and returns the top element, or `None` if the stack is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns the top element, or `None` if the stack is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the number of elements in the stack.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// Sorts a stack using only one auxiliary stack, leaving the smallest element
+/// on top, so popping yields ascending order.
+///
+/// Pop each element off the stack and push it onto the auxiliary stack, which
+/// is kept with its largest element on top. Before pushing, move every element
+/// on the auxiliary stack that is larger than the popped element back onto
+/// the input stack, to be popped and placed again later. Once the input stack
+/// is empty, the auxiliary stack is sorted with the largest on top, so moving
+/// it back reverses it, leaving the smallest on top.
+///
+/// Worst-case performance: O(n^2) comparisons.
+/// Best-case performance: O(n) comparisons.
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn sort_stack<T: Ord>(stack: &mut Stack<T>) {
+    let mut aux = Stack::new();
+
+    while let Some(item) = stack.pop() {
+        while aux.peek().is_some_and(|top| *top > item) {
+            // Safe to unwrap, `peek` returned `Some`.
+            stack.push(aux.pop().unwrap());
+        }
+        aux.push(item);
+    }
+
+    while let Some(item) = aux.pop() {
+        stack.push(item);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stack() {
+        let mut stack = Stack::new();
+        assert!(stack.is_empty());
+        assert_eq!(None, stack.peek());
+
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(Some(&2), stack.peek());
+        assert_eq!(2, stack.len());
+
+        assert_eq!(Some(2), stack.pop());
+        stack.push(3);
+        assert_eq!(Some(3), stack.pop());
+        assert_eq!(Some(1), stack.pop());
+        assert_eq!(None, stack.pop());
+    }
+
+    #[test]
+    fn test_sort_stack() {
+        let mut stack = Stack::new();
+        for i in [5, 1, 9, 3, 7, 2, 8, 6, 4, 0, 3] {
+            stack.push(i);
+        }
+        sort_stack(&mut stack);
+
+        let mut popped = Vec::new();
+        while let Some(i) = stack.pop() {
+            popped.push(i);
+        }
+        assert_eq!(vec![0, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9], popped);
+
+        let mut stack = Stack::new();
+        for s in ["Test", "A old day", "A new day"] {
+            stack.push(s);
+        }
+        sort_stack(&mut stack);
+        assert_eq!(Some("A new day"), stack.pop());
+        assert_eq!(Some("A old day"), stack.pop());
+        assert_eq!(Some("Test"), stack.pop());
+
+        let mut stack: Stack<i32> = Stack::new();
+        sort_stack(&mut stack);
+        assert!(stack.is_empty());
+    }
+}
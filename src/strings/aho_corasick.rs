@@ -0,0 +1,168 @@
+//! # Aho-Corasick multiple pattern search.
+
+use std::collections::{HashMap, VecDeque};
+
+/// The root of the trie, i.e. the empty prefix.
+const ROOT: usize = 0;
+
+/// A set of patterns, compiled into an automaton that finds every occurrence
+/// of any of them in a single pass over a text.
+///
+/// The automaton is a trie of the patterns' bytes, where each node is the
+/// prefix spelled by the path to it, with two extra links per node:
+///
+/// - The failure link, to the node for the longest proper suffix of its prefix
+///   that's also in the trie. On a mismatch, follow failure links until a node
+///   can step on the next byte, or the root is reached. Like the LPS table of
+///   [`kmp_search`](super::kmp::kmp_search), this keeps the scan from ever
+///   moving backwards.
+/// - The output, the patterns that end at the node, either spelling its whole
+///   prefix or a suffix of it reached through failure links.
+///
+/// The failure links are built breadth first, since each node's depends on
+/// its parent's, which is shorter.
+///
+/// New: O(m) for m bytes of patterns in total.
+/// Find all: O(n + z), for a text of n bytes and z matches.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<u8, usize>,
+    fail: usize,
+    // The index of each pattern ending here, longest first.
+    output: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Compiles an automaton matching `patterns`.
+    ///
+    /// Empty patterns match nothing.
+    pub fn new(patterns: &[&str]) -> Self {
+        let mut nodes = vec![Node::default()];
+        for (p, pattern) in patterns.iter().enumerate() {
+            if pattern.is_empty() {
+                continue;
+            }
+            let mut node = ROOT;
+            for &byte in pattern.as_bytes() {
+                node = match nodes[node].children.get(&byte) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(Node::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(byte, child);
+                        child
+                    }
+                };
+            }
+            nodes[node].output.push(p);
+        }
+
+        let mut automaton = Self { nodes };
+        automaton.link_failures();
+        automaton
+    }
+
+    /// Returns `(pattern_index, end)` for every occurrence of any pattern in
+    /// `text`, including overlapping occurrences, where the occurrence is the
+    /// bytes `text[end - pattern.len()..end]`.
+    ///
+    /// Occurrences are ordered by `end`, and those sharing an `end` longest
+    /// first.
+    pub fn find_all(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut matches = Vec::new();
+        let mut node = ROOT;
+        for (i, &byte) in text.as_bytes().iter().enumerate() {
+            node = self.step(node, byte);
+            matches.extend(self.nodes[node].output.iter().map(|&p| (p, i + 1)));
+        }
+        matches
+    }
+
+    /// Returns the node reached from `node` on `byte`, following failure links
+    /// until some node can step on it.
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&child) = self.nodes[node].children.get(&byte) {
+                return child;
+            }
+            if node == ROOT {
+                return ROOT;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// Sets every node's failure link, and extends its output with the output
+    /// of the node it fails to.
+    fn link_failures(&mut self) {
+        // The root's children fail to the root, which `Node::default` already
+        // set, so start from them.
+        let mut queue: VecDeque<usize> = self.nodes[ROOT].children.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> =
+                self.nodes[node].children.iter().map(|(&byte, &child)| (byte, child)).collect();
+            for (byte, child) in children {
+                // Extend the longest proper suffix of the parent's prefix that can
+                // step on `byte`.
+                let fail = self.step(self.nodes[node].fail, byte);
+                self.nodes[child].fail = fail;
+
+                // `fail` is shallower than `child`, so its output is complete.
+                let inherited = self.nodes[fail].output.clone();
+                self.nodes[child].output.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_aho_corasick() {
+        let patterns = ["he", "she", "his", "hers"];
+        let automaton = AhoCorasick::new(&patterns);
+        // u s h e r s
+        //     she, he
+        //       hers
+        assert_eq!(vec![(1, 4), (0, 4), (3, 6)], automaton.find_all("ushers"));
+
+        for (p, end) in automaton.find_all("ahishershe") {
+            let pattern = patterns[p];
+            assert_eq!(pattern, &"ahishershe"[end - pattern.len()..end]);
+        }
+        assert_eq!(
+            vec![(2, 4), (1, 6), (0, 6), (3, 8), (1, 10), (0, 10)],
+            automaton.find_all("ahishershe")
+        );
+        assert!(automaton.find_all("xyz").is_empty());
+        assert!(automaton.find_all("").is_empty());
+    }
+
+    #[test]
+    fn test_aho_corasick_overlapping() {
+        let automaton = AhoCorasick::new(&["a", "aa", "aaa"]);
+        assert_eq!(vec![(0, 1), (1, 2), (0, 2), (2, 3), (1, 3), (0, 3)], automaton.find_all("aaa"));
+
+        // Offsets are in bytes.
+        let automaton = AhoCorasick::new(&["本", "日本"]);
+        assert_eq!(vec![(1, 6), (0, 6), (1, 15), (0, 15)], automaton.find_all("日本日日本"));
+    }
+
+    #[test]
+    fn test_aho_corasick_edge_cases() {
+        assert!(AhoCorasick::new(&[]).find_all("abc").is_empty());
+        assert!(AhoCorasick::new(&[""]).find_all("abc").is_empty());
+
+        // Duplicate patterns both match.
+        let automaton = AhoCorasick::new(&["ab", "", "ab"]);
+        assert_eq!(vec![(0, 2), (2, 2)], automaton.find_all("ab"));
+    }
+}
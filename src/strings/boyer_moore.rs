@@ -0,0 +1,104 @@
+//! # Boyer-Moore string search.
+
+/// Returns the byte offset of every occurrence of `pattern` in `text`,
+/// including overlapping occurrences, using the Boyer-Moore algorithm with the
+/// bad character rule.
+///
+/// Align the pattern with the start of the text and compare it from its last
+/// byte backwards. On a mismatch, shift the pattern right so that the last
+/// occurrence in the pattern of the mismatched text byte lines up with it, or
+/// past it entirely if the pattern doesn't contain that byte. On a match,
+/// shift so the text byte just past the pattern lines up likewise.
+///
+/// The longer the pattern, and the fewer of the text's bytes it contains, the
+/// further each shift skips, up to the whole pattern length. On such input
+/// most of the text is never looked at, so the search is sublinear.
+///
+/// As with [`kmp_search`](super::kmp::kmp_search), offsets are in bytes, and
+/// an empty pattern matches nothing, returning no offsets.
+///
+/// Worst-case performance: O(nm), for a text of n bytes and pattern of m.
+/// Best-case performance: O(n / m).
+/// Worst-case space complexity: O(1) auxiliary, a 256 entry table.
+pub fn boyer_moore(text: &str, pattern: &str) -> Vec<usize> {
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let mut matches = Vec::new();
+    let (n, m) = (text.len(), pattern.len());
+    if m == 0 || m > n {
+        return matches;
+    }
+
+    let last = last_occurrences(pattern);
+    // How far to shift so the last occurrence of `byte` in the pattern lines up
+    // with position `j` of the pattern, and at least one.
+    let shift = |byte: u8, j: usize| match last[byte as usize] {
+        Some(k) if k < j => j - k,
+        Some(_) => 1,
+        None => j + 1,
+    };
+
+    let mut s = 0;
+    while s <= n - m {
+        // Compare from the last byte backwards, to the first mismatch if any.
+        match (0..m).rev().find(|&j| pattern[j] != text[s + j]) {
+            Some(j) => s += shift(text[s + j], j),
+            None => {
+                matches.push(s);
+                s += if s + m < n { shift(text[s + m], m) } else { 1 };
+            }
+        }
+    }
+    matches
+}
+
+/// Returns the index of the last occurrence in `pattern` of each byte value.
+fn last_occurrences(pattern: &[u8]) -> [Option<usize>; 256] {
+    let mut last = [None; 256];
+    for (i, &byte) in pattern.iter().enumerate() {
+        last[byte as usize] = Some(i);
+    }
+    last
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings::kmp::kmp_search;
+
+    #[test]
+    fn test_boyer_moore_shifts() {
+        // A mismatch on a byte absent from the pattern skips past it entirely,
+        // on one whose last occurrence is right of the mismatch shifts by one,
+        // and otherwise lines the two up.
+        for (text, pattern) in [
+            ("xxxxxxabc", "abc"),
+            ("abababcab", "cab"),
+            ("aabaabaab", "aab"),
+            ("bbbbbabbbb", "ab"),
+            ("abcabcabc", "abcabc"),
+            // Empty and too long patterns, empty text, and offsets in bytes.
+            ("abc", ""),
+            ("", ""),
+            ("", "a"),
+            ("ab", "abc"),
+            ("日本日日本", "本"),
+        ] {
+            assert_eq!(kmp_search(text, pattern), boyer_moore(text, pattern));
+        }
+    }
+
+    #[test]
+    fn test_boyer_moore_long_text() {
+        let mut text = "the quick brown fox jumps over the lazy dog. ".repeat(200);
+        text.push_str("needle in a haystack");
+        let pattern = "needle in a haystack";
+        assert_eq!(vec![text.len() - pattern.len()], boyer_moore(&text, pattern));
+        assert!(boyer_moore(&text, "needle in a haystacks").is_empty());
+        assert_eq!(200, boyer_moore(&text, "lazy dog").len());
+
+        // Agrees with the standard library on every occurrence.
+        let expected: Vec<usize> = text.match_indices("the").map(|(i, _)| i).collect();
+        assert_eq!(expected, boyer_moore(&text, "the"));
+        assert_eq!(kmp_search(&text, "o"), boyer_moore(&text, "o"));
+    }
+}
@@ -0,0 +1,79 @@
+//! # caesar cipher.
+
+use std::collections::HashMap;
+
+/// Returns `s` with each ASCII letter rotated `shift` places through the
+/// alphabet, keeping its case. Other chars are left untouched.
+///
+/// A negative shift rotates backwards, and any shift is taken modulo 26, so
+/// shifting by `-k` undoes shifting by `k`.
+///
+/// Worst-case performance: O(n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn caesar_shift(s: &str, shift: i32) -> String {
+    // Always in 0..26, even for a negative shift.
+    let shift = shift.rem_euclid(26) as u8;
+    s.chars()
+        .map(|c| {
+            let base = match c {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return c,
+            };
+            char::from(base + (c as u8 - base + shift) % 26)
+        })
+        .collect()
+}
+
+/// Returns the number of times each char occurs in `s`.
+///
+/// Characters are Unicode scalar values, so a multibyte char counts as one.
+///
+/// Worst-case performance: O(n).
+/// Worst-case space complexity: O(k) auxiliary, for k distinct chars.
+pub fn char_frequencies(s: &str) -> HashMap<char, usize> {
+    let mut freqs = HashMap::new();
+    for c in s.chars() {
+        *freqs.entry(c).or_insert(0) += 1;
+    }
+    freqs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_caesar_shift() {
+        assert_eq!("Khoor, Zruog!", caesar_shift("Hello, World!", 3));
+        assert_eq!("abc", caesar_shift("xyz", 3));
+        assert_eq!("xyz", caesar_shift("abc", -3));
+        assert_eq!("same", caesar_shift("same", 26));
+        assert_eq!("bcd", caesar_shift("abc", 27));
+        assert_eq!("zab", caesar_shift("abc", -27));
+        assert_eq!("日本 123", caesar_shift("日本 123", 5));
+        assert_eq!("", caesar_shift("", 3));
+    }
+
+    #[test]
+    fn test_caesar_shift_round_trip() {
+        let s = "The quick brown fox jumps over the lazy dog. 🦀";
+        assert_eq!(s, caesar_shift(&caesar_shift(s, 3), -3));
+        assert_eq!(s, caesar_shift(&caesar_shift(s, -100), 100));
+        assert_eq!(s, caesar_shift(&caesar_shift(s, i32::MAX), -i32::MAX));
+    }
+
+    #[test]
+    fn test_char_frequencies() {
+        let freqs = char_frequencies("mississippi");
+        assert_eq!(4, freqs.len());
+        assert_eq!(Some(&1), freqs.get(&'m'));
+        assert_eq!(Some(&4), freqs.get(&'i'));
+        assert_eq!(Some(&4), freqs.get(&'s'));
+        assert_eq!(Some(&2), freqs.get(&'p'));
+        assert_eq!(None, freqs.get(&'z'));
+
+        assert_eq!(Some(&2), char_frequencies("日本日").get(&'日'));
+        assert!(char_frequencies("").is_empty());
+    }
+}
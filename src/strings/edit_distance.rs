@@ -0,0 +1,63 @@
+//! # edit distance.
+
+/// Returns the Levenshtein distance between `a` and `b`, i.e. the fewest
+/// single character insertions, deletions and substitutions turning one into
+/// the other.
+///
+/// Fill a table where entry `(i, j)` is the distance between the first `i`
+/// chars of the lhs and the first `j` chars of the rhs. Each entry is the
+/// cheapest of deleting the lhs char, inserting the rhs char, or substituting
+/// one for the other, which is free if they are equal. Each row only depends
+/// on the row before it, so only two rows are kept, each as long as the
+/// shorter string.
+///
+/// Characters are Unicode scalar values, so a multibyte char counts as one.
+///
+/// Worst-case performance: O(nm), for strings of n and m chars.
+/// Worst-case space complexity: O(min(n, m)) auxiliary.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let (mut a, mut b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    // The distance is symmetric, so make the rows as short as possible.
+    if a.len() < b.len() {
+        std::mem::swap(&mut a, &mut b);
+    }
+
+    // The distance from the empty prefix of `a` to each prefix of `b`.
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            curr[j + 1] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(3, levenshtein("kitten", "sitting"));
+        assert_eq!(3, levenshtein("sitting", "kitten"));
+        assert_eq!(2, levenshtein("flaw", "lawn"));
+        assert_eq!(5, levenshtein("intention", "execution"));
+        assert_eq!(0, levenshtein("same", "same"));
+        assert_eq!(0, levenshtein("", ""));
+        assert_eq!(4, levenshtein("", "four"));
+        assert_eq!(4, levenshtein("four", ""));
+    }
+
+    #[test]
+    fn test_levenshtein_unicode() {
+        assert_eq!(1, levenshtein("café", "cafe"));
+        assert_eq!(1, levenshtein("日本", "日本語"));
+        assert_eq!(2, levenshtein("🦀🦀", ""));
+    }
+}
@@ -0,0 +1,93 @@
+//! # Knuth-Morris-Pratt string search.
+
+/// Returns the byte offset of every occurrence of `pattern` in `text`,
+/// including overlapping occurrences, using the Knuth-Morris-Pratt algorithm.
+///
+/// Scan the text while tracking how many bytes of the pattern currently
+/// match. On a mismatch, rather than restarting from the next text position,
+/// fall back using the LPS table: for each prefix of the pattern, the length
+/// of its longest proper prefix that is also a suffix. Those bytes are
+/// already known to match the text, so the scan never moves backwards.
+///
+/// The search is over bytes, but since both strings are valid UTF-8, every
+/// match starts on a char boundary.
+///
+/// An empty pattern matches nothing, and returns no offsets.
+///
+/// Worst-case performance: O(n + m), for a text of n bytes and pattern of m.
+/// Worst-case space complexity: O(m) auxiliary.
+pub fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    let mut matches = Vec::new();
+    if pattern.is_empty() || pattern.len() > text.len() {
+        return matches;
+    }
+
+    let lps = lps_table(pattern);
+    // The number of pattern bytes matching the text ending at `i`.
+    let mut matched = 0;
+    for (i, &byte) in text.iter().enumerate() {
+        while matched > 0 && pattern[matched] != byte {
+            matched = lps[matched - 1];
+        }
+        if pattern[matched] == byte {
+            matched += 1;
+        }
+        if matched == pattern.len() {
+            matches.push(i + 1 - matched);
+            // Keep going from the longest border, to find overlapping matches.
+            matched = lps[matched - 1];
+        }
+    }
+    matches
+}
+
+/// Returns the LPS table of `pattern`, where entry `i` is the length of the
+/// longest proper prefix of `pattern[..=i]` that is also its suffix.
+fn lps_table(pattern: &[u8]) -> Vec<usize> {
+    let mut lps = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[len] != pattern[i] {
+            len = lps[len - 1];
+        }
+        if pattern[len] == pattern[i] {
+            len += 1;
+        }
+        lps[i] = len;
+    }
+    lps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kmp_search() {
+        assert_eq!(vec![0, 1, 2], kmp_search("aaaaa", "aaa"));
+        assert_eq!(vec![0, 7], kmp_search("abcabd abcabd", "abcabd"));
+        assert_eq!(vec![9], kmp_search("abababcabababcd", "ababcd"));
+        assert_eq!(vec![3], kmp_search("the cat", " cat"));
+        assert!(kmp_search("the cat", "dog").is_empty());
+        assert_eq!(vec![0], kmp_search("same", "same"));
+
+        // Offsets are in bytes.
+        assert_eq!(vec![3, 12], kmp_search("日本日日本", "本"));
+    }
+
+    #[test]
+    fn test_kmp_search_edge_cases() {
+        assert!(kmp_search("abc", "").is_empty());
+        assert!(kmp_search("", "").is_empty());
+        assert!(kmp_search("", "a").is_empty());
+        assert!(kmp_search("ab", "abc").is_empty());
+    }
+
+    #[test]
+    fn test_lps_table() {
+        assert_eq!(vec![0, 1, 2, 3, 4], lps_table(b"aaaaa"));
+        assert_eq!(vec![0, 0, 1, 2, 0], lps_table(b"ababc"));
+        assert_eq!(vec![0, 1, 0, 1, 2, 2, 3], lps_table(b"aabaaab"));
+    }
+}
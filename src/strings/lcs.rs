@@ -0,0 +1,103 @@
+//! # longest common subsequence.
+
+/// Returns a longest common subsequence of `a` and `b`, i.e. a longest string
+/// whose chars appear in both, in order but not necessarily adjacent.
+///
+/// Fill a table where entry `(i, j)` is the LCS length of the first `i` chars
+/// of the lhs and the first `j` chars of the rhs. If those prefixes end in the
+/// same char, it extends the LCS of both prefixes without it, otherwise the
+/// LCS is the longer of dropping the last char of either side. Then recover a
+/// subsequence by backtracking from the last entry, taking a char each time
+/// both sides end in it, and otherwise stepping towards the longer neighbour.
+///
+/// If there are several longest common subsequences, any one may be returned.
+///
+/// Worst-case performance: O(nm), for strings of n and m chars.
+/// Worst-case space complexity: O(nm) auxiliary.
+pub fn lcs(a: &str, b: &str) -> String {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+
+    let width = b.len() + 1;
+    let mut table = vec![0; (a.len() + 1) * width];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i * width + j] = if a[i - 1] == b[j - 1] {
+                table[(i - 1) * width + j - 1] + 1
+            } else {
+                table[(i - 1) * width + j].max(table[i * width + j - 1])
+            };
+        }
+    }
+
+    let mut subsequence = Vec::with_capacity(table[a.len() * width + b.len()]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            subsequence.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if table[(i - 1) * width + j] >= table[i * width + j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    subsequence.iter().rev().collect()
+}
+
+/// Returns the length, in chars, of a longest common subsequence of `a` and
+/// `b`.
+///
+/// See [`lcs`]. Without the need to backtrack, only the previous row of the
+/// table is kept, each as long as the shorter string.
+///
+/// Worst-case performance: O(nm), for strings of n and m chars.
+/// Worst-case space complexity: O(min(n, m)) auxiliary.
+pub fn lcs_len(a: &str, b: &str) -> usize {
+    let (mut a, mut b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    if a.len() < b.len() {
+        std::mem::swap(&mut a, &mut b);
+    }
+
+    let mut prev = vec![0; b.len() + 1];
+    let mut curr = vec![0; b.len() + 1];
+    for ca in &a {
+        for (j, cb) in b.iter().enumerate() {
+            curr[j + 1] = if ca == cb { prev[j] + 1 } else { prev[j + 1].max(curr[j]) };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns `true` if the chars of `sub` appear in `s` in order.
+    fn is_subsequence(sub: &str, s: &str) -> bool {
+        let mut chars = s.chars();
+        sub.chars().all(|c| chars.any(|d| c == d))
+    }
+
+    #[test]
+    fn test_lcs() {
+        for (a, b, len) in [
+            ("ABCBDAB", "BDCAB", 4),
+            ("AGGTAB", "GXTXAYB", 4),
+            ("abc", "abc", 3),
+            ("abc", "def", 0),
+            ("", "abc", 0),
+            ("", "", 0),
+            ("naïve", "native", 4),
+        ] {
+            let sub = lcs(a, b);
+            assert_eq!(len, sub.chars().count());
+            assert!(is_subsequence(&sub, a));
+            assert!(is_subsequence(&sub, b));
+            assert_eq!(len, lcs_len(a, b));
+            assert_eq!(len, lcs_len(b, a));
+        }
+        assert_eq!("GTAB", lcs("AGGTAB", "GXTXAYB"));
+    }
+}
@@ -0,0 +1,15 @@
+//! # string algorithms.
+//!
+//! `strings` defines various searching and comparison algorithms on strings.
+
+pub mod aho_corasick;
+pub mod boyer_moore;
+pub mod caesar;
+pub mod edit_distance;
+pub mod kmp;
+pub mod lcs;
+pub mod palindrome;
+pub mod rabin_karp;
+pub mod rle;
+pub mod suffix_array;
+pub mod z_algorithm;
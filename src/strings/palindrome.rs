@@ -0,0 +1,84 @@
+//! # palindromes.
+
+/// Returns the longest palindromic substring of `s`, using Manacher's
+/// algorithm.
+///
+/// Interleave the chars with separators, e.g. "abba" becomes "|a|b|b|a|", so
+/// that every palindrome, odd or even length, has odd length and a single
+/// center. Then for each center, find the radius of the longest palindrome
+/// around it. A palindrome mirrors the radii within it: the radius at a
+/// center inside the rightmost palindrome found so far is at least that of
+/// its mirror image, up to that palindrome's rhs edge. So expansion only ever
+/// has to compare chars past the rhs edge, which only moves rightwards.
+///
+/// A radius in the interleaved string is the length, in chars, of the
+/// palindrome in `s`. The chars are Unicode scalar values, so the palindrome's
+/// char offsets are mapped back to byte offsets to slice `s`.
+///
+/// If there are several longest palindromes, the first is returned.
+///
+/// Worst-case performance: O(n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn longest_palindrome(s: &str) -> &str {
+    // The byte offset of each char, and of the end of `s`.
+    let offsets: Vec<usize> = s.char_indices().map(|(i, _)| i).chain([s.len()]).collect();
+    let chars: Vec<char> = s.chars().collect();
+
+    // The chars interleaved with `None` separators.
+    let mut interleaved = vec![None; 2 * chars.len() + 1];
+    for (i, &c) in chars.iter().enumerate() {
+        interleaved[2 * i + 1] = Some(c);
+    }
+
+    let mut radii = vec![0; interleaved.len()];
+    // The center and rhs edge, exclusive, of the rightmost palindrome found.
+    let (mut center, mut right) = (0, 0);
+    let (mut best_center, mut best_radius) = (0, 0);
+    for i in 0..interleaved.len() {
+        let mut radius = if i < right { radii[2 * center - i].min(right - i) } else { 0 };
+        while i > radius
+            && i + radius + 1 < interleaved.len()
+            && interleaved[i - radius - 1] == interleaved[i + radius + 1]
+        {
+            radius += 1;
+        }
+        radii[i] = radius;
+
+        if i + radius > right {
+            center = i;
+            right = i + radius;
+        }
+        if radius > best_radius {
+            best_center = i;
+            best_radius = radius;
+        }
+    }
+
+    let start = (best_center - best_radius) / 2;
+    &s[offsets[start]..offsets[start + best_radius]]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_longest_palindrome() {
+        assert_eq!("bab", longest_palindrome("babad"));
+        assert_eq!("bb", longest_palindrome("cbbd"));
+        assert_eq!("racecar", longest_palindrome("xracecary"));
+        assert_eq!("abba", longest_palindrome("xabbay"));
+        assert_eq!("aaaa", longest_palindrome("aaaa"));
+        assert_eq!("geeksskeeg", longest_palindrome("forgeeksskeegfor"));
+        assert_eq!("a", longest_palindrome("abc"));
+        assert_eq!("a", longest_palindrome("a"));
+        assert_eq!("", longest_palindrome(""));
+    }
+
+    #[test]
+    fn test_longest_palindrome_unicode() {
+        assert_eq!("日本日", longest_palindrome("x日本日y"));
+        assert_eq!("éé", longest_palindrome("aéé"));
+        assert_eq!("🦀a🦀", longest_palindrome("🦀a🦀b"));
+    }
+}
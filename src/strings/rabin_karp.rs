@@ -0,0 +1,142 @@
+//! # Rabin-Karp string search.
+
+use std::collections::HashMap;
+
+/// The base of the polynomial hash, one more than the largest byte value.
+const BASE: u64 = 256;
+
+/// The modulus of the polynomial hash, a large prime so that distinct windows
+/// rarely collide. Small enough that `hash * BASE` can't overflow a `u64`.
+const MODULUS: u64 = 1_000_000_007;
+
+/// Returns the byte offset of every occurrence of `pattern` in `text`,
+/// including overlapping occurrences, using the Rabin-Karp algorithm.
+///
+/// Slide a window the length of the pattern along the text, and compare the
+/// hash of each window against the hash of the pattern, only comparing bytes
+/// where the hashes match. The hash is a polynomial in the window's bytes,
+/// modulo a large prime, so it can be rolled along by one byte in O(1):
+/// subtract the outgoing byte's term, shift the rest up a power, and add the
+/// incoming byte.
+///
+/// A match of hashes may be a collision, so every candidate is verified by
+/// comparing bytes, and only true matches are returned.
+///
+/// As with [`kmp_search`](super::kmp::kmp_search), offsets are in bytes, and
+/// an empty pattern matches nothing, returning no offsets.
+///
+/// Worst-case performance: O(nm), for a text of n bytes and pattern of m,
+/// when every window collides.
+/// Average performance: O(n + m).
+/// Worst-case space complexity: O(1) auxiliary.
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    rabin_karp_multi(text, &[pattern]).into_iter().map(|(offset, _)| offset).collect()
+}
+
+/// Returns the byte offset of every occurrence of each of `patterns` in
+/// `text`, as `(offset, pattern index)` pairs ordered by offset, then index.
+///
+/// See [`rabin_karp`]. Each window's hash is looked up among the hashes of
+/// all the patterns, so every pattern is searched for in the one pass.
+///
+/// Panics if the patterns aren't all the same length.
+///
+/// Average performance: O(n + km), for k patterns.
+/// Worst-case space complexity: O(k) auxiliary.
+pub fn rabin_karp_multi(text: &str, patterns: &[&str]) -> Vec<(usize, usize)> {
+    search(text.as_bytes(), patterns, MODULUS)
+}
+
+/// Shared implementation of [`rabin_karp_multi`], hashing modulo `modulus`.
+fn search(text: &[u8], patterns: &[&str], modulus: u64) -> Vec<(usize, usize)> {
+    let mut matches = Vec::new();
+    let Some(m) = patterns.first().map(|p| p.len()) else {
+        return matches;
+    };
+    assert!(patterns.iter().all(|p| p.len() == m), "patterns must all be the same length");
+    if m == 0 || m > text.len() {
+        return matches;
+    }
+
+    // The indices of the patterns with each hash.
+    let mut candidates: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, pattern) in patterns.iter().enumerate() {
+        candidates.entry(hash(pattern.as_bytes(), modulus)).or_default().push(i);
+    }
+
+    // The weight of the outgoing byte, i.e. `BASE^(m - 1)`.
+    let high = (1..m).fold(1, |acc, _| acc * BASE % modulus);
+
+    let mut window = hash(&text[..m], modulus);
+    for s in 0..=text.len() - m {
+        for &i in candidates.get(&window).into_iter().flatten() {
+            // Verify the candidate, since distinct bytes can share a hash.
+            if &text[s..s + m] == patterns[i].as_bytes() {
+                matches.push((s, i));
+            }
+        }
+
+        if s + m < text.len() {
+            // Add `modulus` before subtracting, to stay non-negative.
+            let rest = (window + modulus - text[s] as u64 * high % modulus) % modulus;
+            window = (rest * BASE + text[s + m] as u64) % modulus;
+        }
+    }
+    matches
+}
+
+/// Returns the polynomial hash of `bytes` modulo `modulus`.
+fn hash(bytes: &[u8], modulus: u64) -> u64 {
+    bytes.iter().fold(0, |acc, &byte| (acc * BASE + byte as u64) % modulus)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings::kmp::kmp_search;
+
+    #[test]
+    fn test_rabin_karp() {
+        let text = "the quick brown fox jumps over the lazy dog. ".repeat(50);
+        let expected: Vec<usize> = text.match_indices("the").map(|(i, _)| i).collect();
+        assert_eq!(expected, rabin_karp(&text, "the"));
+        assert_eq!(kmp_search(&text, "o"), rabin_karp(&text, "o"));
+
+        for (text, pattern) in
+            [("abc", ""), ("", ""), ("", "a"), ("ab", "abc"), ("日本日日本", "本")]
+        {
+            assert_eq!(kmp_search(text, pattern), rabin_karp(text, pattern));
+        }
+    }
+
+    #[test]
+    fn test_rabin_karp_collisions() {
+        // With a modulus of 3, most windows collide with the pattern's hash, but
+        // only the true matches are returned.
+        let text = "abcabcabdabcab".as_bytes();
+        let collisions =
+            (0..=text.len() - 3).filter(|&s| hash(&text[s..s + 3], 3) == hash(b"abd", 3));
+        assert!(collisions.count() > 1);
+        assert_eq!(vec![(6, 0)], search(text, &["abd"], 3));
+
+        // Every window has the same hash modulo 1.
+        assert_eq!(vec![(0, 0), (3, 0), (9, 0)], search(text, &["abc"], 1));
+    }
+
+    #[test]
+    fn test_rabin_karp_multi() {
+        let text = "she sells sea shells";
+        assert_eq!(
+            vec![(0, 0), (4, 1), (10, 2), (14, 0)],
+            rabin_karp_multi(text, &["she", "sel", "sea", "zzz"])
+        );
+        assert_eq!(vec![(0, 0), (0, 1), (14, 0), (14, 1)], rabin_karp_multi(text, &["she", "she"]));
+        assert!(rabin_karp_multi(text, &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_rabin_karp_multi_unequal_lengths() {
+        rabin_karp_multi("text", &["a", "ab"]);
+    }
+}
@@ -0,0 +1,75 @@
+//! # run-length encoding.
+
+/// Returns the run-length encoding of `s`, i.e. each maximal run of a
+/// repeated char paired with its length, in order.
+///
+/// Characters are Unicode scalar values, so a multibyte char counts as one.
+/// An empty string has no runs.
+///
+/// Worst-case performance: O(n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn rle_encode(s: &str) -> Vec<(char, usize)> {
+    let mut runs: Vec<(char, usize)> = Vec::new();
+    for c in s.chars() {
+        match runs.last_mut() {
+            Some((last, count)) if *last == c => *count += 1,
+            _ => runs.push((c, 1)),
+        }
+    }
+    runs
+}
+
+/// Returns the string whose run-length encoding is `runs`, repeating each
+/// char by its run's length.
+///
+/// Runs needn't be maximal, so adjacent runs of the same char and runs of
+/// length 0 are accepted.
+///
+/// Worst-case performance: O(n), for a decoded string of n chars.
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn rle_decode(runs: &[(char, usize)]) -> String {
+    runs.iter().flat_map(|&(c, count)| std::iter::repeat_n(c, count)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rle_encode() {
+        assert_eq!(vec![('a', 3), ('b', 3), ('c', 3), ('d', 1)], rle_encode("aaabbbcccd"));
+        assert_eq!(vec![('a', 1), ('b', 1), ('a', 1)], rle_encode("aba"));
+        assert_eq!(vec![('x', 4)], rle_encode("xxxx"));
+        assert_eq!(vec![('日', 2), ('🦀', 1)], rle_encode("日日🦀"));
+        assert!(rle_encode("").is_empty());
+    }
+
+    #[test]
+    fn test_rle_decode() {
+        assert_eq!("aaabbbcccd", rle_decode(&[('a', 3), ('b', 3), ('c', 3), ('d', 1)]));
+        assert_eq!("aaa", rle_decode(&[('a', 1), ('a', 2), ('b', 0)]));
+        assert_eq!("", rle_decode(&[]));
+    }
+}
+
+#[cfg(test)]
+mod prop_test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn prop_rle_round_trip(s in any::<String>()) {
+            prop_assert_eq!(&s, &rle_decode(&rle_encode(&s)));
+        }
+
+        // Small alphabets give long runs.
+        #[test]
+        fn prop_rle_round_trip_runs(s in "[ab]{0,100}") {
+            let runs = rle_encode(&s);
+            prop_assert!(runs.windows(2).all(|w| w[0].0 != w[1].0));
+            prop_assert_eq!(&s, &rle_decode(&runs));
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! # suffix array.
+
+/// Returns the byte offset of every suffix of `s`, in the sorted order of the
+/// suffixes, using prefix doubling.
+///
+/// Rank each suffix by its first byte. Then, for k = 1, 2, 4, ..., rank each
+/// suffix by its first 2k bytes, which is the pair of ranks of its first k
+/// bytes and of the k bytes after them, i.e. of the suffix k further on. A
+/// suffix too short to have one ranks below every other in that position.
+/// Once every rank is distinct, the ranks are the sorted order.
+///
+/// The pairs of ranks are bounded by n, so each round sorts them with two
+/// counting sort passes, by second rank then stably by first rank, as in a
+/// radix sort. Each round doubles k, so there are at most log n rounds.
+///
+/// Sorting bytes rather than chars gives the same order, since UTF-8 preserves
+/// the order of code points.
+///
+/// Worst-case performance: O(n log n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn suffix_array(s: &str) -> Vec<usize> {
+    let bytes = s.as_bytes();
+    let n = bytes.len();
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // Ranks start at 1, leaving 0 for a suffix too short to have a second half.
+    let mut rank: Vec<usize> = bytes.iter().map(|&byte| usize::from(byte) + 1).collect();
+    let mut max_rank = usize::from(u8::MAX) + 1;
+    let mut suffixes: Vec<usize> = (0..n).collect();
+    let mut k = 1;
+    loop {
+        let second = |i: usize| if i + k < n { rank[i + k] } else { 0 };
+        suffixes = counting_sort_by_key(&suffixes, max_rank, second);
+        suffixes = counting_sort_by_key(&suffixes, max_rank, |i| rank[i]);
+
+        // Suffixes with equal pairs share a rank, the rest count up from 1.
+        let mut next_rank = vec![0; n];
+        next_rank[suffixes[0]] = 1;
+        for w in suffixes.windows(2) {
+            let distinct = (rank[w[0]], second(w[0])) != (rank[w[1]], second(w[1]));
+            next_rank[w[1]] = next_rank[w[0]] + usize::from(distinct);
+        }
+        rank = next_rank;
+        max_rank = rank[suffixes[n - 1]];
+
+        if max_rank == n {
+            return suffixes;
+        }
+        k *= 2;
+    }
+}
+
+/// Stably sorts `suffixes` by `key`, which must be at most `max_key`.
+fn counting_sort_by_key<F>(suffixes: &[usize], max_key: usize, key: F) -> Vec<usize>
+where
+    F: Fn(usize) -> usize,
+{
+    let mut counts = vec![0; max_key + 1];
+    for &i in suffixes {
+        counts[key(i)] += 1;
+    }
+
+    // Turn the counts into the starting index of each key's bucket.
+    let mut start = 0;
+    for count in counts.iter_mut() {
+        let n = *count;
+        *count = start;
+        start += n;
+    }
+
+    let mut sorted = vec![0; suffixes.len()];
+    for &i in suffixes {
+        let k = key(i);
+        sorted[counts[k]] = i;
+        counts[k] += 1;
+    }
+    sorted
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Returns the suffix array of `s` by sorting its suffixes directly.
+    fn naive_suffix_array(s: &str) -> Vec<usize> {
+        let mut suffixes: Vec<usize> = (0..s.len()).collect();
+        suffixes.sort_by_key(|&i| &s.as_bytes()[i..]);
+        suffixes
+    }
+
+    #[test]
+    fn test_suffix_array() {
+        // 5 a
+        // 3 ana
+        // 1 anana
+        // 0 banana
+        // 4 na
+        // 2 nana
+        assert_eq!(vec![5, 3, 1, 0, 4, 2], suffix_array("banana"));
+        assert_eq!(vec![3, 2, 1, 0], suffix_array("aaaa"));
+        assert_eq!(vec![0], suffix_array("a"));
+        assert!(suffix_array("").is_empty());
+
+        for s in ["mississippi", "abracadabra", "the quick brown fox", "日本日日本", "abcabcabc"]
+        {
+            assert_eq!(naive_suffix_array(s), suffix_array(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_suffix_array_long() {
+        // Few distinct bytes, so the ranks take many rounds to separate.
+        let mut state: u32 = 0x2545_f491;
+        let s: String = (0..5000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (b'a' + (state % 3) as u8) as char
+            })
+            .collect();
+        assert_eq!(naive_suffix_array(&s), suffix_array(&s));
+    }
+}
@@ -0,0 +1,107 @@
+//! # Z-algorithm.
+
+/// Returns the Z-array of `s`, where entry `i` is the length of the longest
+/// substring starting at `i` that is also a prefix of `s`.
+///
+/// Entry 0 is the length of `s`, since the whole string is a prefix of
+/// itself. Indices and lengths are in bytes.
+///
+/// Worst-case performance: O(n).
+/// Worst-case space complexity: O(n) auxiliary.
+pub fn z_array(s: &str) -> Vec<usize> {
+    z_values(s.as_bytes())
+}
+
+/// Returns the byte offset of every occurrence of `pattern` in `text`,
+/// including overlapping occurrences, using the Z-algorithm.
+///
+/// Compute the Z-array of the pattern, a separator, and the text, joined
+/// together. The separator matches nothing, so no entry can exceed the
+/// pattern's length, and an entry equal to it marks an occurrence of the
+/// pattern in the text. The separator is distinct from every byte, rather
+/// than a char that might appear in the text.
+///
+/// As with [`kmp_search`](super::kmp::kmp_search), offsets are in bytes, and
+/// an empty pattern matches nothing, returning no offsets.
+///
+/// Worst-case performance: O(n + m), for a text of n bytes and pattern of m.
+/// Worst-case space complexity: O(n + m) auxiliary.
+pub fn z_search(text: &str, pattern: &str) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return Vec::new();
+    }
+
+    // The bytes of each joined by a `None` separator.
+    let joined: Vec<Option<u8>> =
+        pattern.bytes().map(Some).chain([None]).chain(text.bytes().map(Some)).collect();
+    z_values(&joined)
+        .into_iter()
+        .enumerate()
+        .skip(m + 1)
+        .filter(|&(_, z)| z == m)
+        .map(|(i, _)| i - m - 1)
+        .collect()
+}
+
+/// Returns the Z-array of `s`.
+///
+/// Track the rightmost window `l..r` found to match a prefix. Within it, `s`
+/// repeats its prefix, so the entry at `i` is at least that at `i - l`, up to
+/// the window's rhs edge. Only bytes past the edge need comparing, and the
+/// edge only moves rightwards.
+fn z_values<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        if i < r {
+            z[i] = z[i - l].min(r - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > r {
+            l = i;
+            r = i + z[i];
+        }
+    }
+    z
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strings::kmp::kmp_search;
+
+    #[test]
+    fn test_z_array() {
+        assert_eq!(
+            vec![19, 1, 0, 0, 4, 1, 0, 0, 0, 8, 1, 0, 0, 5, 1, 0, 0, 1, 0],
+            z_array("aabxaabxcaabxaabxay")
+        );
+        assert_eq!(vec![5, 4, 3, 2, 1], z_array("aaaaa"));
+        assert_eq!(vec![6, 0, 4, 0, 2, 0], z_array("ababab"));
+        assert!(z_array("").is_empty());
+    }
+
+    #[test]
+    fn test_z_search() {
+        // A `$` in the text doesn't confuse the separator.
+        assert_eq!(vec![0, 2], z_search("a$a$", "a$"));
+
+        let text = "aabxaabxcaabxaabxay";
+        assert_eq!(kmp_search(text, "aabxa"), z_search(text, "aabxa"));
+
+        for (text, pattern) in
+            [("abc", ""), ("", ""), ("", "a"), ("ab", "abc"), ("日本日日本", "本")]
+        {
+            assert_eq!(kmp_search(text, pattern), z_search(text, pattern));
+        }
+    }
+}
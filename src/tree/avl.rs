@@ -0,0 +1,363 @@
+//! # AVL tree.
+
+use std::cmp::Ordering;
+
+/// An ordered set backed by an AVL self-balancing binary search tree.
+///
+/// An AVL tree is a binary search tree where the heights of every node's two
+/// subtrees differ by at most one. After each insert or remove, walk back up
+/// the path to the root, and wherever a node's subtrees differ in height by
+/// two, rotate it to restore the balance:
+///
+/// - Left-left, the lhs subtree is taller through its own lhs child, so rotate
+///   the node right.
+/// - Left-right, the lhs subtree is taller through its rhs child, so rotate the
+///   lhs child left, then the node right.
+/// - Right-right and right-left mirror these.
+///
+/// This keeps the height below 1.45 log2(n), so unlike
+/// [`BinarySearchTree`](super::bst::BinarySearchTree) no insertion order can
+/// degrade it into a list.
+///
+/// Insert: O(log n).
+/// Contains: O(log n).
+/// Remove: O(log n).
+#[derive(Debug)]
+pub struct AvlTree<T: Ord> {
+    root: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    // The number of nodes on the longest path down to a leaf, including this
+    // one.
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T: Ord> AvlTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Inserts `value` into the tree.
+    ///
+    /// Returns `false` if the tree already contained an equal value, in which
+    /// case the tree is unchanged.
+    pub fn insert(&mut self, value: T) -> bool {
+        let inserted = insert(&mut self.root, value);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Returns `true` if the tree contains `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match value.cmp(&node.value) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /// Removes `value` from the tree.
+    ///
+    /// Returns `false` if the tree didn't contain `value`.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let removed = remove(&mut self.root, value);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Returns the height of the tree, i.e. the number of nodes on the longest
+    /// path from the root down to a leaf, or 0 if the tree is empty.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns an iterator over the values in ascending order.
+    pub fn in_order(&self) -> InOrder<'_, T> {
+        let mut iter = InOrder { stack: Vec::new() };
+        iter.push_left_spine(&self.root);
+        iter
+    }
+}
+
+impl<T: Ord> Default for AvlTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The tree is balanced, so recursing once per level is bounded by O(log n).
+
+/// Inserts `value` into the subtree at `link`, rebalancing on the way back up.
+fn insert<T: Ord>(link: &mut Link<T>, value: T) -> bool {
+    let Some(node) = link else {
+        *link = Some(Box::new(Node { value, height: 1, left: None, right: None }));
+        return true;
+    };
+
+    let inserted = match value.cmp(&node.value) {
+        Ordering::Less => insert(&mut node.left, value),
+        Ordering::Greater => insert(&mut node.right, value),
+        Ordering::Equal => false,
+    };
+    if inserted {
+        rebalance(link);
+    }
+    inserted
+}
+
+/// Removes `value` from the subtree at `link`, rebalancing on the way back up.
+fn remove<T: Ord>(link: &mut Link<T>, value: &T) -> bool {
+    let Some(node) = link else {
+        return false;
+    };
+
+    let removed = match value.cmp(&node.value) {
+        Ordering::Less => remove(&mut node.left, value),
+        Ordering::Greater => remove(&mut node.right, value),
+        Ordering::Equal => {
+            // Safe to unwrap, matched `Some` above.
+            let mut node = link.take().unwrap();
+            *link = match (node.left.take(), node.right.take()) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                // Replace the value with its in-order successor.
+                (Some(left), Some(right)) => {
+                    let mut right = Some(right);
+                    node.value = take_min(&mut right);
+                    node.left = Some(left);
+                    node.right = right;
+                    Some(node)
+                }
+            };
+            true
+        }
+    };
+    if removed {
+        rebalance(link);
+    }
+    removed
+}
+
+/// Removes and returns the smallest value of the non-empty subtree at `link`,
+/// rebalancing on the way back up.
+fn take_min<T: Ord>(link: &mut Link<T>) -> T {
+    // Safe to unwrap, the subtree is non-empty.
+    let node = link.as_mut().unwrap();
+    if node.left.is_some() {
+        let min = take_min(&mut node.left);
+        rebalance(link);
+        min
+    } else {
+        let node = link.take().unwrap();
+        *link = node.right;
+        node.value
+    }
+}
+
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn update_height<T>(node: &mut Node<T>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+}
+
+/// Returns the lhs height minus the rhs height.
+fn balance_factor<T>(node: &Node<T>) -> isize {
+    height(&node.left) as isize - height(&node.right) as isize
+}
+
+/// Restores the AVL property at `link`, given its subtrees are balanced and
+/// differ in height by at most two.
+fn rebalance<T>(link: &mut Link<T>) {
+    let Some(node) = link else {
+        return;
+    };
+    update_height(node);
+
+    match balance_factor(node) {
+        2 => {
+            // Safe to unwrap, the lhs is taller so it's non-empty.
+            if balance_factor(node.left.as_ref().unwrap()) < 0 {
+                rotate_left(&mut node.left);
+            }
+            rotate_right(link);
+        }
+        -2 => {
+            // Safe to unwrap, the rhs is taller so it's non-empty.
+            if balance_factor(node.right.as_ref().unwrap()) > 0 {
+                rotate_right(&mut node.right);
+            }
+            rotate_left(link);
+        }
+        _ => {}
+    }
+}
+
+/// Rotates the subtree at `link` right, making its lhs child the new root.
+///
+/// ```text
+///       n            l
+///      / \          / \
+///     l   c   =>   a   n
+///    / \              / \
+///   a   b            b   c
+/// ```
+fn rotate_right<T>(link: &mut Link<T>) {
+    // Safe to unwrap, only called on a node with a lhs child.
+    let mut node = link.take().unwrap();
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    update_height(&mut node);
+    left.right = Some(node);
+    update_height(&mut left);
+    *link = Some(left);
+}
+
+/// Rotates the subtree at `link` left, making its rhs child the new root.
+///
+/// ```text
+///     n                r
+///    / \              / \
+///   a   r      =>    n   c
+///      / \          / \
+///     b   c        a   b
+/// ```
+fn rotate_left<T>(link: &mut Link<T>) {
+    // Safe to unwrap, only called on a node with a rhs child.
+    let mut node = link.take().unwrap();
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    update_height(&mut node);
+    right.left = Some(node);
+    update_height(&mut right);
+    *link = Some(right);
+}
+
+/// An iterator over the values of an [`AvlTree`] in ascending order.
+pub struct InOrder<'a, T> {
+    // The nodes whose value and rhs subtree are yet to be visited, with the
+    // next node on top.
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> InOrder<'a, T> {
+    /// Pushes the node at `link` and all of its lhs descendants.
+    fn push_left_spine(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(&node.right);
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Asserts every node is balanced and has the correct height, returning the
+    /// height of the subtree at `link`.
+    fn assert_balanced<T>(link: &Link<T>) -> usize {
+        let Some(node) = link else {
+            return 0;
+        };
+        let (left, right) = (assert_balanced(&node.left), assert_balanced(&node.right));
+        assert!(left.abs_diff(right) <= 1);
+        assert_eq!(1 + left.max(right), node.height);
+        node.height
+    }
+
+    #[test]
+    fn test_avl_tree() {
+        let mut tree = AvlTree::new();
+        assert!(tree.is_empty());
+        assert_eq!(0, tree.height());
+
+        for i in [50, 30, 70, 20, 40, 60, 80, 35] {
+            assert!(tree.insert(i));
+        }
+        assert!(!tree.insert(40));
+        assert_eq!(8, tree.len());
+        assert!(tree.contains(&35));
+        assert!(!tree.contains(&36));
+        assert_balanced(&tree.root);
+
+        assert!(tree.remove(&50));
+        assert!(tree.remove(&20));
+        assert!(!tree.remove(&20));
+        assert_eq!(vec![30, 35, 40, 60, 70, 80], tree.in_order().copied().collect::<Vec<_>>());
+        assert_balanced(&tree.root);
+    }
+
+    #[test]
+    fn test_avl_tree_ascending_inserts() {
+        let mut tree = AvlTree::new();
+        for i in 1..=1000 {
+            tree.insert(i);
+        }
+        // A perfectly balanced tree of 1000 nodes has height 10, an unbalanced
+        // one 1000.
+        assert!(tree.height() <= 20);
+        assert_balanced(&tree.root);
+        assert!(tree.in_order().copied().eq(1..=1000));
+
+        // Removing from one side forces rotations the other way.
+        for i in 1..=500 {
+            assert!(tree.remove(&i));
+        }
+        assert_eq!(500, tree.len());
+        assert!(tree.height() <= 20);
+        assert_balanced(&tree.root);
+        assert!(tree.in_order().copied().eq(501..=1000));
+    }
+
+    #[test]
+    fn test_avl_tree_rotations() {
+        // Left-left, right-right, left-right and right-left respectively.
+        for values in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+            let mut tree = AvlTree::new();
+            for v in values {
+                tree.insert(v);
+            }
+            assert_eq!(2, tree.height());
+            assert_eq!(Some(2), tree.root.as_ref().map(|node| node.value));
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! # binary search tree.
+
+use std::cmp::Ordering;
+
+/// An ordered set backed by an unbalanced binary search tree.
+///
+/// Every node's lhs subtree holds only smaller values, and its rhs subtree only
+/// larger values, so a value can be found by walking down from the root and
+/// going left or right depending on the comparison at each node, much like
+/// binary search.
+///
+/// Nothing keeps the tree balanced, so its height and the cost of each
+/// operation depends on insertion order. Inserting in sorted order degrades
+/// the tree into a list.
+///
+/// Insert: O(h), for a tree of height h, O(log n) average, O(n) worst-case.
+/// Contains: O(h).
+/// Remove: O(h).
+#[derive(Debug)]
+pub struct BinarySearchTree<T: Ord> {
+    root: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+#[derive(Debug)]
+struct Node<T> {
+    value: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Inserts `value` into the tree.
+    ///
+    /// Returns `false` if the tree already contained an equal value, in which
+    /// case the tree is unchanged.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            match value.cmp(&node.value) {
+                Ordering::Less => link = &mut node.left,
+                Ordering::Greater => link = &mut node.right,
+                Ordering::Equal => return false,
+            }
+        }
+        *link = Some(Box::new(Node { value, left: None, right: None }));
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if the tree contains `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            match value.cmp(&node.value) {
+                Ordering::Less => link = &node.left,
+                Ordering::Greater => link = &node.right,
+                Ordering::Equal => return true,
+            }
+        }
+        false
+    }
+
+    /// Removes `value` from the tree.
+    ///
+    /// Returns `false` if the tree didn't contain `value`.
+    ///
+    /// There are three cases for the node to remove:
+    ///
+    /// - A leaf, which is simply removed.
+    /// - A node with one child, which is replaced by that child.
+    /// - A node with two children, whose value is replaced by its in-order
+    ///   successor, i.e. the smallest value in its rhs subtree. The successor
+    ///   has no lhs child, so it is then removed by one of the cases above.
+    pub fn remove(&mut self, value: &T) -> bool {
+        // Find the link to the node to remove. Comparing before reborrowing
+        // keeps `link` free to reassign on each step.
+        let mut link = &mut self.root;
+        loop {
+            match link.as_ref().map(|node| value.cmp(&node.value)) {
+                None => return false,
+                Some(Ordering::Equal) => break,
+                // Safe to unwrap, the comparison above was `Some`.
+                Some(Ordering::Less) => link = &mut link.as_mut().unwrap().left,
+                Some(Ordering::Greater) => link = &mut link.as_mut().unwrap().right,
+            }
+        }
+
+        // Safe to unwrap, the loop only breaks on a found node.
+        let mut node = link.take().unwrap();
+        *link = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let (successor, right) = Self::take_min(right);
+                node.value = successor;
+                node.left = Some(left);
+                node.right = right;
+                Some(node)
+            }
+        };
+        self.len -= 1;
+        true
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns an iterator over the values in ascending order.
+    pub fn in_order(&self) -> InOrder<'_, T> {
+        let mut iter = InOrder { stack: Vec::new() };
+        iter.push_left_spine(&self.root);
+        iter
+    }
+
+    /// Removes the smallest value from `subtree`, returning it and what remains
+    /// of the subtree.
+    fn take_min(mut subtree: Box<Node<T>>) -> (T, Link<T>) {
+        // The root is the smallest, so its rhs subtree is what remains.
+        if subtree.left.is_none() {
+            return (subtree.value, subtree.right);
+        }
+
+        // Otherwise find the parent of the leftmost node, and replace the
+        // leftmost node with its rhs subtree.
+        let mut parent = &mut subtree;
+        while parent.left.as_ref().is_some_and(|left| left.left.is_some()) {
+            // Safe to unwrap, checked above.
+            parent = parent.left.as_mut().unwrap();
+        }
+        // Safe to unwrap, `parent` has a lhs child.
+        let min = parent.left.take().unwrap();
+        parent.left = min.right;
+        (min.value, Some(subtree))
+    }
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Drop for BinarySearchTree<T> {
+    /// Drops the nodes one at a time.
+    ///
+    /// The default drop recurses once per level, which overflows the stack for
+    /// a tree degraded into a long list.
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+/// An iterator over the values of a [`BinarySearchTree`] in ascending order.
+pub struct InOrder<'a, T> {
+    // The nodes whose value and rhs subtree are yet to be visited, with the
+    // next node on top.
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> InOrder<'a, T> {
+    /// Pushes the node at `link` and all of its lhs descendants.
+    fn push_left_spine(&mut self, mut link: &'a Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(&node.right);
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree_of(values: &[i32]) -> BinarySearchTree<i32> {
+        let mut tree = BinarySearchTree::new();
+        for &v in values {
+            tree.insert(v);
+        }
+        tree
+    }
+
+    #[test]
+    fn test_bst_insert_contains() {
+        let mut tree = BinarySearchTree::new();
+        assert!(tree.is_empty());
+        assert!(tree.insert(5));
+        assert!(tree.insert(3));
+        assert!(tree.insert(8));
+        assert!(!tree.insert(3));
+        assert_eq!(3, tree.len());
+
+        assert!(tree.contains(&5));
+        assert!(tree.contains(&3));
+        assert!(tree.contains(&8));
+        assert!(!tree.contains(&4));
+    }
+
+    #[test]
+    fn test_bst_in_order() {
+        let tree = tree_of(&[50, 30, 70, 20, 40, 60, 80, 35, 45, 65, 10]);
+        let values: Vec<_> = tree.in_order().copied().collect();
+        assert_eq!(vec![10, 20, 30, 35, 40, 45, 50, 60, 65, 70, 80], values);
+
+        let values: Vec<i32> = (0..1000).map(|i| (i * 7919) % 1000).collect();
+        let tree = tree_of(&values);
+        assert!(tree.in_order().copied().eq(0..1000));
+
+        let tree: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert_eq!(None, tree.in_order().next());
+    }
+
+    #[test]
+    fn test_bst_remove_leaf() {
+        let mut tree = tree_of(&[50, 30, 70, 20]);
+        assert!(tree.remove(&20));
+        assert!(!tree.contains(&20));
+        assert_eq!(vec![30, 50, 70], tree.in_order().copied().collect::<Vec<_>>());
+        assert!(!tree.remove(&20));
+        assert_eq!(3, tree.len());
+    }
+
+    #[test]
+    fn test_bst_remove_one_child() {
+        let mut tree = tree_of(&[50, 30, 70, 20, 80]);
+        // 30 only has a lhs child, 70 only has a rhs child.
+        assert!(tree.remove(&30));
+        assert!(tree.remove(&70));
+        assert_eq!(vec![20, 50, 80], tree.in_order().copied().collect::<Vec<_>>());
+        assert!(tree.contains(&20) && tree.contains(&80));
+    }
+
+    #[test]
+    fn test_bst_remove_two_children() {
+        let mut tree = tree_of(&[50, 30, 70, 20, 40, 60, 80, 65]);
+        // The successor of 50 is 60, which has a rhs child of its own.
+        assert!(tree.remove(&50));
+        assert_eq!(vec![20, 30, 40, 60, 65, 70, 80], tree.in_order().copied().collect::<Vec<_>>());
+        // The successor of 30 is 40, its immediate rhs child.
+        assert!(tree.remove(&30));
+        assert_eq!(vec![20, 40, 60, 65, 70, 80], tree.in_order().copied().collect::<Vec<_>>());
+        assert_eq!(6, tree.len());
+
+        let mut tree = tree_of(&[1, 2]);
+        assert!(tree.remove(&1));
+        assert!(tree.remove(&2));
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_bst_drop_degenerate() {
+        // Sorted inserts degrade the tree into a list, 20_000 levels deep.
+        let mut tree = BinarySearchTree::new();
+        for i in 0..20_000 {
+            tree.insert(i);
+        }
+        drop(tree);
+    }
+}
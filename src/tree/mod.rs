@@ -0,0 +1,6 @@
+//! # tree data structures.
+//!
+//! `tree` defines various tree data structures on generic types.
+
+pub mod avl;
+pub mod bst;
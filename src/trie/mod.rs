@@ -0,0 +1,147 @@
+//! # trie data structures.
+//!
+//! `trie` defines a prefix tree over strings.
+
+use std::collections::HashMap;
+
+/// A set of strings stored as a prefix tree.
+///
+/// Each node represents the prefix spelled out by the path from the root to
+/// it, with one child per character that extends the prefix, and a flag for
+/// whether the prefix is itself a stored word. Words sharing a prefix share
+/// the nodes for it, so prefix queries only need to walk down to the prefix's
+/// node.
+///
+/// Children are keyed by `char` so any Unicode text can be stored.
+///
+/// Insert: O(m), for a word of m chars.
+/// Contains: O(m).
+/// Starts with: O(m).
+#[derive(Debug, Default)]
+pub struct Trie {
+    root: Node,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    // Whether the path to this node spells a stored word.
+    is_word: bool,
+}
+
+impl Trie {
+    /// Creates an empty trie.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts `word` into the trie.
+    pub fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word = true;
+    }
+
+    /// Returns `true` if `word` was inserted into the trie.
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_word)
+    }
+
+    /// Returns `true` if any inserted word starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+
+    /// Returns every inserted word that starts with `prefix`, in no particular
+    /// order.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let Some(node) = self.find(prefix) else {
+            return words;
+        };
+
+        // Walk the subtree under the prefix, carrying the word spelled so far.
+        let mut stack = vec![(node, prefix.to_string())];
+        while let Some((node, word)) = stack.pop() {
+            for (&c, child) in &node.children {
+                let mut word = word.clone();
+                word.push(c);
+                stack.push((child, word));
+            }
+            if node.is_word {
+                words.push(word);
+            }
+        }
+        words
+    }
+
+    /// Returns the node for `prefix`, if any inserted word starts with it.
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        // Only the root can be reached without being on the path to a word.
+        (node.is_word || !node.children.is_empty()).then_some(node)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn trie_of(words: &[&str]) -> Trie {
+        let mut trie = Trie::new();
+        for word in words {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn test_trie_contains() {
+        let trie = trie_of(&["cat", "car", "card", "dog"]);
+        assert!(trie.contains("cat"));
+        assert!(trie.contains("card"));
+        assert!(!trie.contains("ca"));
+        assert!(!trie.contains("cards"));
+        assert!(!trie.contains(""));
+
+        let trie = trie_of(&["", "héllo", "日本"]);
+        assert!(trie.contains(""));
+        assert!(trie.contains("héllo"));
+        assert!(trie.contains("日本"));
+        assert!(!trie.contains("日"));
+    }
+
+    #[test]
+    fn test_trie_starts_with() {
+        let trie = trie_of(&["cat", "car", "card", "dog"]);
+        assert!(trie.starts_with("ca"));
+        assert!(trie.starts_with("card"));
+        assert!(trie.starts_with("d"));
+        assert!(trie.starts_with(""));
+        assert!(!trie.starts_with("z"));
+        assert!(!trie.starts_with("cart"));
+
+        assert!(!Trie::new().starts_with(""));
+    }
+
+    #[test]
+    fn test_trie_words_with_prefix() {
+        let trie = trie_of(&["cat", "car", "card", "dog"]);
+
+        let mut words = trie.words_with_prefix("ca");
+        words.sort();
+        assert_eq!(vec!["car", "card", "cat"], words);
+
+        assert_eq!(vec!["card"], trie.words_with_prefix("card"));
+        assert!(trie.words_with_prefix("z").is_empty());
+
+        let mut words = trie.words_with_prefix("");
+        words.sort();
+        assert_eq!(vec!["car", "card", "cat", "dog"], words);
+    }
+}